@@ -38,6 +38,26 @@ pub enum DataKey {
     AssetIndex(Address),
     /// Cached reserve configs
     ReserveConfig(Address),
+    /// Registered asset addresses, in registration order
+    Assets,
+    /// Validate and log Blend submissions without calling the pool
+    DryRun,
+    /// Seconds a computed health factor stays cached (0 = no caching)
+    HealthCacheTtl,
+    /// Last computed health factor per user
+    HealthCache(Address),
+    /// Cached Blend pool config
+    PoolConfig,
+}
+
+/// Health factor cached with the ledger time it was computed
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CachedHealthFactor {
+    /// Health factor result as last computed
+    pub result: HealthFactorResult,
+    /// Ledger timestamp of the computation
+    pub computed_at: u64,
 }
 
 /// Adapter errors
@@ -61,6 +81,14 @@ pub enum AdapterError {
     UnhealthyPosition = 7,
     /// Already initialized
     AlreadyInitialized = 8,
+    /// Invalid configuration parameters
+    InvalidParams = 9,
+    /// Request would open more positions than the pool's `max_positions`
+    MaxPositionsExceeded = 10,
+    /// No reserve config has been cached for the asset
+    ReserveConfigNotCached = 11,
+    /// No pool config has been cached
+    PoolConfigNotCached = 12,
 }
 
 #[contract]
@@ -75,21 +103,37 @@ impl BlendAdapterContract {
     /// * `blend_pool` - Address of the Blend pool to interact with
     /// * `oracle` - Oracle contract for price feeds
     /// * `usdc_token` - USDC token address for borrowing
+    ///
+    /// # Errors
+    /// - `InvalidParams`: Any two of the addresses are equal
     pub fn initialize(
         env: Env,
         admin: Address,
         blend_pool: Address,
         oracle: Address,
         usdc_token: Address,
-    ) {
+    ) -> Result<(), AdapterError> {
         if env.storage().instance().has(&DataKey::Admin) {
             panic!("Already initialized");
         }
 
+        let addresses = vec![
+            &env,
+            admin.clone(),
+            blend_pool.clone(),
+            oracle.clone(),
+            usdc_token.clone(),
+        ];
+        if !vantis_types::addresses_distinct(&addresses) {
+            return Err(AdapterError::InvalidParams);
+        }
+
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::BlendPool, &blend_pool);
         env.storage().instance().set(&DataKey::Oracle, &oracle);
         env.storage().instance().set(&DataKey::UsdcToken, &usdc_token);
+
+        Ok(())
     }
 
     /// Register a supported collateral asset
@@ -107,6 +151,16 @@ impl BlendAdapterContract {
         caller.require_auth();
         Self::require_admin(&env, &caller)?;
 
+        let mut assets: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Assets)
+            .unwrap_or(Vec::new(&env));
+        if !assets.contains(&asset) {
+            assets.push_back(asset.clone());
+            env.storage().instance().set(&DataKey::Assets, &assets);
+        }
+
         env.storage()
             .persistent()
             .set(&DataKey::AssetIndex(asset.clone()), &reserve_index);
@@ -119,12 +173,73 @@ impl BlendAdapterContract {
         Ok(())
     }
 
+    /// Cache the Blend reserve configuration for a registered asset
+    ///
+    /// # Arguments
+    /// * `caller` - Must be admin
+    /// * `asset` - Registered asset address
+    /// * `config` - Reserve configuration as set on the Blend pool
+    pub fn cache_reserve_config(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        config: ReserveConfig,
+    ) -> Result<(), AdapterError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        Self::require_asset_supported(&env, &asset)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::ReserveConfig(asset.clone()), &config);
+
+        env.events().publish(
+            (symbol_short!("reserve"), symbol_short!("cached")),
+            (&asset, config.index),
+        );
+
+        Ok(())
+    }
+
+    /// Cache the Blend pool configuration
+    ///
+    /// Submissions are checked against its `max_positions`, so keep it in
+    /// step with the pool.
+    ///
+    /// # Arguments
+    /// * `caller` - Must be admin
+    /// * `config` - Pool configuration as set on the Blend pool
+    pub fn cache_pool_config(
+        env: Env,
+        caller: Address,
+        config: PoolConfig,
+    ) -> Result<(), AdapterError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if config.max_positions == 0 {
+            return Err(AdapterError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::PoolConfig, &config);
+
+        env.events().publish(
+            (symbol_short!("pool"), symbol_short!("cached")),
+            config.max_positions,
+        );
+
+        Ok(())
+    }
+
     // ============ Collateral Operations ============
 
     /// Deposit collateral into the Blend pool
     ///
     /// This transfers the asset from the user to this contract, then
-    /// submits a SupplyCollateral request to the Blend pool.
+    /// submits a SupplyCollateral request to the Blend pool. A user holding
+    /// less than `amount` gets `InsufficientBalance` up front. A failed submit
+    /// returns `BlendOperationFailed`, which rolls back the whole call, so the
+    /// transfer and the pool's allowance are undone with it.
     ///
     /// # Arguments
     /// * `user` - User depositing collateral
@@ -147,12 +262,24 @@ impl BlendAdapterContract {
 
         // Transfer asset from user to this contract
         let token_client = token::Client::new(&env, &asset);
-        token_client.transfer(&user, &env.current_contract_address(), &amount);
+        if token_client.balance(&user) < amount {
+            return Err(AdapterError::InsufficientBalance);
+        }
 
-        // Approve Blend pool to spend the tokens
-        // Set expiration to current ledger + 1000 ledgers (about 1.4 hours)
-        let expiration_ledger = env.ledger().sequence() + 1000;
-        token_client.approve(&env.current_contract_address(), &blend_pool, &amount, &expiration_ledger);
+        // A dry run never reaches the pool, so the tokens stay with the user
+        if !Self::is_dry_run(env.clone()) {
+            token_client.transfer(&user, &env.current_contract_address(), &amount);
+
+            // Approve Blend pool to spend the tokens
+            // Set expiration to current ledger + 1000 ledgers (about 1.4 hours)
+            let expiration_ledger = env.ledger().sequence() + 1000;
+            token_client.approve(
+                &env.current_contract_address(),
+                &blend_pool,
+                &amount,
+                &expiration_ledger,
+            );
+        }
 
         // Build and submit the request to Blend
         let request = Request {
@@ -245,6 +372,9 @@ impl BlendAdapterContract {
 
     /// Repay borrowed USDC to the Blend pool
     ///
+    /// Fails with `InsufficientBalance` before any transfer if the user holds
+    /// less than `amount` USDC.
+    ///
     /// # Arguments
     /// * `user` - User repaying
     /// * `amount` - Amount of USDC to repay
@@ -261,12 +391,24 @@ impl BlendAdapterContract {
 
         // Transfer USDC from user to this contract
         let token_client = token::Client::new(&env, &usdc_address);
-        token_client.transfer(&user, &env.current_contract_address(), &amount);
+        if token_client.balance(&user) < amount {
+            return Err(AdapterError::InsufficientBalance);
+        }
 
-        // Approve Blend pool to spend the tokens
-        // Set expiration to current ledger + 1000 ledgers (about 1.4 hours)
-        let expiration_ledger = env.ledger().sequence() + 1000;
-        token_client.approve(&env.current_contract_address(), &blend_pool, &amount, &expiration_ledger);
+        // A dry run never reaches the pool, so the tokens stay with the user
+        if !Self::is_dry_run(env.clone()) {
+            token_client.transfer(&user, &env.current_contract_address(), &amount);
+
+            // Approve Blend pool to spend the tokens
+            // Set expiration to current ledger + 1000 ledgers (about 1.4 hours)
+            let expiration_ledger = env.ledger().sequence() + 1000;
+            token_client.approve(
+                &env.current_contract_address(),
+                &blend_pool,
+                &amount,
+                &expiration_ledger,
+            );
+        }
 
         // Build and submit the request to Blend
         let request = Request {
@@ -369,50 +511,81 @@ impl BlendAdapterContract {
     ///
     /// Health factor = (collateral value * collateral factor) / liability value
     /// Returns value in basis points (10000 = 1.0)
+    ///
+    /// With a health cache TTL set, a result computed within the last TTL
+    /// seconds is returned without re-reading positions. Operations that
+    /// change the user's position invalidate the cache.
     pub fn get_health_factor(env: Env, user: Address) -> Result<HealthFactorResult, AdapterError> {
-        let _positions = Self::get_positions(env.clone(), user)?;
+        let ttl = Self::get_health_cache_ttl(env.clone());
+        let now = env.ledger().timestamp();
 
-        // In production, this would:
-        // 1. Get prices from oracle for each asset
-        // 2. Get collateral factors from reserve configs
-        // 3. Calculate weighted collateral value
-        // 4. Calculate total liability value
-        // 5. Compute health factor
+        if ttl > 0 {
+            let cached: Option<CachedHealthFactor> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::HealthCache(user.clone()));
+            if let Some(cached) = cached {
+                if now.saturating_sub(cached.computed_at) < ttl {
+                    return Ok(cached.result);
+                }
+            }
+        }
 
-        // Placeholder calculation
-        let total_collateral: i128 = 0;
-        let total_liabilities: i128 = 0;
+        let result = Self::compute_health_factor(&env, &user)?;
 
-        let health_factor = if total_liabilities == 0 {
-            i128::MAX
-        } else {
-            total_collateral * 10000 / total_liabilities
-        };
+        if ttl > 0 {
+            env.storage().persistent().set(
+                &DataKey::HealthCache(user),
+                &CachedHealthFactor {
+                    result: result.clone(),
+                    computed_at: now,
+                },
+            );
+        }
 
-        Ok(HealthFactorResult {
-            health_factor,
-            total_collateral,
-            total_liabilities,
-            is_liquidatable: health_factor < 10000 && total_liabilities > 0,
-        })
+        Ok(result)
+    }
+
+    /// Drop a user's cached health factor so the next read recomputes it
+    ///
+    /// Called internally after every Blend submission; callable by anyone
+    /// since it can only make the next read fresher.
+    pub fn invalidate_health_cache(env: Env, user: Address) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::HealthCache(user));
+    }
+
+    /// Set how long a computed health factor stays cached (admin only)
+    ///
+    /// # Arguments
+    /// * `ttl` - Seconds (0 disables caching)
+    pub fn set_health_cache_ttl(env: Env, caller: Address, ttl: u64) -> Result<(), AdapterError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        env.storage().instance().set(&DataKey::HealthCacheTtl, &ttl);
+        Ok(())
+    }
+
+    /// Get how long a computed health factor stays cached (0 = no caching)
+    pub fn get_health_cache_ttl(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HealthCacheTtl)
+            .unwrap_or(0)
     }
 
     /// Get Blend pool configuration
+    ///
+    /// Served from the config cached with `cache_pool_config`, since the
+    /// Blend pool client exposes no config getter. Fails with
+    /// `PoolConfigNotCached` until the admin has cached it.
     pub fn get_pool_config(env: Env) -> Result<PoolConfig, AdapterError> {
-        let _blend_pool = Self::get_blend_pool(&env)?;
-
-        // In production, call blend_pool.get_config()
-        // Placeholder return
-        Ok(PoolConfig {
-            oracle: env
-                .storage()
-                .instance()
-                .get(&DataKey::Oracle)
-                .unwrap_or(env.current_contract_address()),
-            bstop_rate: 100,
-            status: 0,
-            max_positions: 10,
-        })
+        env.storage()
+            .instance()
+            .get(&DataKey::PoolConfig)
+            .ok_or(AdapterError::PoolConfigNotCached)
     }
 
     /// Get reserve data for an asset
@@ -433,12 +606,76 @@ impl BlendAdapterContract {
         })
     }
 
-    /// Get list of reserve addresses in the Blend pool
+    /// Get the static reserve configuration for an asset
+    ///
+    /// Served from the config cached with `cache_reserve_config`, since the
+    /// Blend pool client exposes no reserve getter. Fails with
+    /// `ReserveConfigNotCached` until the admin has cached it.
+    pub fn get_reserve_config(env: Env, asset: Address) -> Result<ReserveConfig, AdapterError> {
+        Self::require_asset_supported(&env, &asset)?;
+
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReserveConfig(asset))
+            .ok_or(AdapterError::ReserveConfigNotCached)
+    }
+
+    /// Get list of reserve addresses registered with the adapter
+    ///
+    /// Assets are returned in registration order, as maintained by
+    /// `register_asset`.
     pub fn get_reserve_list(env: Env) -> Result<Vec<Address>, AdapterError> {
         let _blend_pool = Self::get_blend_pool(&env)?;
 
-        // In production, call blend_pool.get_reserve_list()
-        Ok(Vec::new(&env))
+        Ok(env
+            .storage()
+            .instance()
+            .get(&DataKey::Assets)
+            .unwrap_or(Vec::new(&env)))
+    }
+
+    /// Get every registered asset with its reserve index and cached config
+    ///
+    /// Returns `(asset, reserve_index, reserve_config)` in registration order.
+    /// Assets without a cached config report a zeroed config carrying only
+    /// the reserve index.
+    pub fn get_supported_assets(env: Env) -> Vec<(Address, u32, ReserveConfig)> {
+        let assets: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Assets)
+            .unwrap_or(Vec::new(&env));
+
+        let mut supported = Vec::new(&env);
+        for asset in assets.iter() {
+            let index: u32 = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AssetIndex(asset.clone()))
+                .unwrap_or(0);
+
+            let config: ReserveConfig = env
+                .storage()
+                .persistent()
+                .get(&DataKey::ReserveConfig(asset.clone()))
+                .unwrap_or(ReserveConfig {
+                    index,
+                    decimals: 0,
+                    c_factor: 0,
+                    l_factor: 0,
+                    util: 0,
+                    max_util: 0,
+                    r_base: 0,
+                    r_one: 0,
+                    r_two: 0,
+                    r_three: 0,
+                    reactivity: 0,
+                });
+
+            supported.push_back((asset, index, config));
+        }
+
+        supported
     }
 
     // ============ Admin Functions ============
@@ -469,8 +706,59 @@ impl BlendAdapterContract {
         Ok(())
     }
 
+    /// Enable or disable dry-run mode (admin only)
+    ///
+    /// In dry-run mode every operation builds and validates its Blend
+    /// requests and emits them in a `("submit", "dryrun")` event, but the
+    /// Blend pool is never called and no tokens are moved or approved.
+    /// Intended for staging integrations.
+    pub fn set_dry_run(env: Env, caller: Address, enabled: bool) -> Result<(), AdapterError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        env.storage().instance().set(&DataKey::DryRun, &enabled);
+        Ok(())
+    }
+
+    /// Check whether dry-run mode is enabled
+    pub fn is_dry_run(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::DryRun).unwrap_or(false)
+    }
+
     // ============ Internal Functions ============
 
+    /// Compute a user's health factor from their current Blend positions
+    fn compute_health_factor(
+        env: &Env,
+        user: &Address,
+    ) -> Result<HealthFactorResult, AdapterError> {
+        let _positions = Self::get_positions(env.clone(), user.clone())?;
+
+        // In production, this would:
+        // 1. Get prices from oracle for each asset
+        // 2. Get collateral factors from reserve configs
+        // 3. Calculate weighted collateral value
+        // 4. Calculate total liability value
+        // 5. Compute health factor
+
+        // Placeholder calculation
+        let total_collateral: i128 = 0;
+        let total_liabilities: i128 = 0;
+
+        let health_factor = if total_liabilities == 0 {
+            i128::MAX
+        } else {
+            total_collateral * 10000 / total_liabilities
+        };
+
+        Ok(HealthFactorResult {
+            health_factor,
+            total_collateral,
+            total_liabilities,
+            is_liquidatable: health_factor < 10000 && total_liabilities > 0,
+        })
+    }
+
     fn require_admin(env: &Env, caller: &Address) -> Result<(), AdapterError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if *caller != admin {
@@ -504,6 +792,51 @@ impl BlendAdapterContract {
             .ok_or(AdapterError::PoolNotConfigured)
     }
 
+    /// Reject requests that would exceed the pool's `max_positions`
+    ///
+    /// Blend counts each collateral and each liability reserve as one
+    /// position. `SupplyCollateral` and `Borrow` requests for reserves the
+    /// user does not hold yet open a new one; assets without a registered
+    /// reserve index are always counted as new.
+    fn require_within_max_positions(
+        env: &Env,
+        blend_pool: &Address,
+        user: &Address,
+        requests: &Vec<Request>,
+    ) -> Result<(), AdapterError> {
+        let max_positions = Self::get_pool_config(env.clone())?.max_positions;
+
+        let positions = pool::Client::new(env, blend_pool).get_positions(user);
+        let mut collateral = positions.collateral;
+        let mut liabilities = positions.liabilities;
+        let mut count = collateral.len() + liabilities.len();
+
+        for request in requests.iter() {
+            let held = match request.request_type {
+                RequestType::SupplyCollateral => &mut collateral,
+                RequestType::Borrow => &mut liabilities,
+                _ => continue,
+            };
+
+            let index: Option<u32> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::AssetIndex(request.address.clone()));
+            match index {
+                Some(index) if held.contains_key(index) => continue,
+                Some(index) => held.set(index, request.amount),
+                None => {}
+            }
+            count += 1;
+        }
+
+        if count > max_positions {
+            return Err(AdapterError::MaxPositionsExceeded);
+        }
+
+        Ok(())
+    }
+
     /// Submit requests to the Blend pool
     ///
     /// Calls the Blend pool's submit function:
@@ -533,6 +866,18 @@ impl BlendAdapterContract {
             blend_requests.push_back(blend_request);
         }
 
+        // Checked before the dry-run exit so a dry run fails where a real one would
+        Self::require_within_max_positions(env, &blend_pool, from, requests)?;
+
+        // Dry run: report the would-be submission and stop before the pool call
+        if Self::is_dry_run(env.clone()) {
+            env.events().publish(
+                (symbol_short!("submit"), symbol_short!("dryrun")),
+                (from, to, requests.clone()),
+            );
+            return Ok(());
+        }
+
         // Collect unique token addresses from requests and sum amounts per token
         let mut token_amounts = soroban_sdk::Map::new(env);
         for request in requests.iter() {
@@ -562,9 +907,14 @@ impl BlendAdapterContract {
         // Use the Blend SDK to submit requests to the pool with error handling
         let pool_client = pool::Client::new(env, &blend_pool);
 
-        // Attempt to submit to Blend pool
-        // If this fails, it will panic in the SDK, but we log before attempting
-        pool_client.submit(from, &env.current_contract_address(), to, &blend_requests);
+        // A failed submit surfaces as BlendOperationFailed so callers can unwind
+        match pool_client.try_submit(from, &env.current_contract_address(), to, &blend_requests) {
+            Ok(Ok(_)) => {}
+            _ => return Err(AdapterError::BlendOperationFailed),
+        }
+
+        // The position changed, so a cached health factor is stale
+        Self::invalidate_health_cache(env.clone(), from.clone());
 
         // Log successful submission
         env.events().publish(
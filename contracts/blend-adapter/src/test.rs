@@ -11,7 +11,72 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use soroban_sdk::{
+    contract,
+    testutils::{Address as _, Events, Ledger},
+    Env, IntoVal, Map, TryFromVal,
+};
+
+/// Minimal Blend pool stand-in that accepts requests and reports preset positions
+#[contract]
+pub struct MockBlendPool;
+
+#[contractimpl]
+impl MockBlendPool {
+    pub fn set_positions(
+        env: Env,
+        address: Address,
+        collateral: Map<u32, i128>,
+        liabilities: Map<u32, i128>,
+    ) {
+        env.storage().instance().set(&address, &(collateral, liabilities));
+    }
+
+    pub fn get_positions(env: Env, address: Address) -> pool::Positions {
+        let (collateral, liabilities) = env
+            .storage()
+            .instance()
+            .get(&address)
+            .unwrap_or((Map::new(&env), Map::new(&env)));
+        pool::Positions {
+            collateral,
+            liabilities,
+            supply: Map::new(&env),
+        }
+    }
+
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage().instance().set(&symbol_short!("failing"), &failing);
+    }
+
+    pub fn submit(
+        env: Env,
+        _from: Address,
+        _spender: Address,
+        _to: Address,
+        _requests: Vec<pool::Request>,
+    ) -> pool::Positions {
+        if env
+            .storage()
+            .instance()
+            .get(&symbol_short!("failing"))
+            .unwrap_or(false)
+        {
+            panic!("submit rejected");
+        }
+        Self::get_positions(env.clone(), env.current_contract_address())
+    }
+}
+
+/// Pool config as a freshly deployed Blend pool reports it
+fn default_pool_config(oracle: &Address) -> PoolConfig {
+    PoolConfig {
+        oracle: oracle.clone(),
+        bstop_rate: 100,
+        status: 0,
+        max_positions: 10,
+    }
+}
 
 // ============ Initialization Tests ============
 
@@ -29,7 +94,7 @@ fn test_initialize() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     assert_eq!(client.admin(), admin);
-    assert_eq!(client.blend_pool().unwrap(), blend_pool);
+    assert_eq!(client.blend_pool(), blend_pool);
 }
 
 #[test]
@@ -49,6 +114,27 @@ fn test_cannot_reinitialize() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 }
 
+#[test]
+fn test_initialize_rejects_duplicate_addresses() {
+    let env = Env::default();
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    // Oracle wired where the USDC token belongs
+    let result = client.try_initialize(&admin, &blend_pool, &oracle, &oracle);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidParams);
+
+    // Admin must not double as a contract address
+    let result = client.try_initialize(&admin, &admin, &oracle, &Address::generate(&env));
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidParams);
+
+    assert!(client.try_admin().is_err());
+}
+
 // ============ Asset Registration Tests ============
 
 #[test]
@@ -68,7 +154,7 @@ fn test_register_asset() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Register XLM as collateral with reserve index 0
-    let result = client.register_asset(&admin, &xlm, &0);
+    let result = client.try_register_asset(&admin, &xlm, &0);
     assert!(result.is_ok());
 }
 
@@ -90,8 +176,42 @@ fn test_register_multiple_assets() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Register multiple assets
-    assert!(client.register_asset(&admin, &xlm, &0).is_ok());
-    assert!(client.register_asset(&admin, &btc, &1).is_ok());
+    assert!(client.try_register_asset(&admin, &xlm, &0).is_ok());
+    assert!(client.try_register_asset(&admin, &btc, &1).is_ok());
+}
+
+#[test]
+fn test_reserve_list_contains_registered_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let btc = Address::generate(&env);
+    let eth = Address::generate(&env);
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    assert_eq!(client.get_reserve_list().len(), 0);
+
+    client.register_asset(&admin, &xlm, &0);
+    client.register_asset(&admin, &btc, &1);
+    client.register_asset(&admin, &eth, &2);
+
+    let reserves = client.get_reserve_list();
+    assert_eq!(reserves.len(), 3);
+    assert_eq!(reserves.get(0).unwrap(), xlm);
+    assert_eq!(reserves.get(1).unwrap(), btc);
+    assert_eq!(reserves.get(2).unwrap(), eth);
+
+    // Re-registering an asset updates its index without duplicating it
+    client.register_asset(&admin, &btc, &3);
+    assert_eq!(client.get_reserve_list().len(), 3);
 }
 
 #[test]
@@ -112,11 +232,69 @@ fn test_register_asset_unauthorized() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Non-admin should not be able to register assets
-    let result = client.register_asset(&unauthorized, &xlm, &0);
+    let result = client.try_register_asset(&unauthorized, &xlm, &0);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::Unauthorized);
 }
 
+#[test]
+fn test_get_supported_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let btc = Address::generate(&env);
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+
+    client.register_asset(&admin, &xlm, &0);
+    client.register_asset(&admin, &btc, &1);
+
+    let xlm_config = ReserveConfig {
+        index: 0,
+        decimals: 7,
+        c_factor: 7500,
+        l_factor: 10000,
+        util: 8000,
+        max_util: 9500,
+        r_base: 100,
+        r_one: 400,
+        r_two: 2000,
+        r_three: 7500,
+        reactivity: 20,
+    };
+    let btc_config = ReserveConfig {
+        index: 1,
+        decimals: 8,
+        c_factor: 6000,
+        ..xlm_config.clone()
+    };
+    client.cache_reserve_config(&admin, &xlm, &xlm_config);
+    client.cache_reserve_config(&admin, &btc, &btc_config);
+
+    let supported = client.get_supported_assets();
+    assert_eq!(supported.len(), 2);
+
+    let (asset, index, config) = supported.get(0).unwrap();
+    assert_eq!(asset, xlm);
+    assert_eq!(index, 0);
+    assert_eq!(config.c_factor, 7500);
+    assert_eq!(config.decimals, 7);
+
+    let (asset, index, config) = supported.get(1).unwrap();
+    assert_eq!(asset, btc);
+    assert_eq!(index, 1);
+    assert_eq!(config.c_factor, 6000);
+    assert_eq!(config.decimals, 8);
+}
+
 // ============ Collateral Operation Tests ============
 
 #[test]
@@ -135,19 +313,209 @@ fn test_deposit_collateral_invalid_amount() {
     let xlm = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
-    client.register_asset(&admin, &xlm, &0).unwrap();
+    client.register_asset(&admin, &xlm, &0);
 
     // Test zero amount
-    let result = client.deposit_collateral(&user, &xlm, &0);
+    let result = client.try_deposit_collateral(&user, &xlm, &0);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 
     // Test negative amount
-    let result = client.deposit_collateral(&user, &xlm, &-100);
+    let result = client.try_deposit_collateral(&user, &xlm, &-100);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 }
 
+#[test]
+fn test_deposit_collateral_dry_run() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    // Only queried for positions; a dry run never submits
+    let blend_pool = env.register(MockBlendPool, ());
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+    let xlm = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+    client.register_asset(&admin, &xlm, &0);
+    token::StellarAssetClient::new(&env, &xlm).mint(&user, &1000_0000000);
+
+    // Only admin can toggle dry-run mode
+    let result = client.try_set_dry_run(&user, &true);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::Unauthorized);
+
+    client.set_dry_run(&admin, &true);
+    assert!(client.is_dry_run());
+
+    client.deposit_collateral(&user, &xlm, &100_0000000);
+
+    let topics: Vec<soroban_sdk::Val> =
+        (symbol_short!("submit"), symbol_short!("dryrun")).into_val(&env);
+    let mut dry_run_events: Vec<(Address, Address, Vec<Request>)> = Vec::new(&env);
+    for (_, event_topics, data) in env.events().all().iter() {
+        if event_topics == topics {
+            dry_run_events.push_back(TryFromVal::try_from_val(&env, &data).unwrap());
+        }
+    }
+
+    assert_eq!(dry_run_events.len(), 1);
+    let (from, to, requests) = dry_run_events.get(0).unwrap();
+    assert_eq!(from, user);
+    assert_eq!(to, user);
+    assert_eq!(
+        requests,
+        vec![
+            &env,
+            Request {
+                request_type: RequestType::SupplyCollateral,
+                address: xlm.clone(),
+                amount: 100_0000000,
+            }
+        ]
+    );
+
+    // Nothing was submitted, so no tokens left the user
+    let xlm_client = token::Client::new(&env, &xlm);
+    assert_eq!(xlm_client.balance(&user), 1000_0000000);
+    assert_eq!(xlm_client.balance(&contract_id), 0);
+    assert_eq!(xlm_client.allowance(&contract_id, &blend_pool), 0);
+}
+
+#[test]
+fn test_deposit_collateral_max_positions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
+    let pool_client = MockBlendPoolClient::new(&env, &blend_pool);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+    let held = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let extra = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+    client.register_asset(&admin, &usdc, &9);
+    client.register_asset(&admin, &held, &0);
+    for index in 1..9u32 {
+        client.register_asset(&admin, &Address::generate(&env), &index);
+    }
+    client.register_asset(&admin, &extra, &10);
+    token::StellarAssetClient::new(&env, &held).mint(&user, &1000_0000000);
+    token::StellarAssetClient::new(&env, &extra).mint(&user, &1000_0000000);
+
+    // Nine collateral reserves plus a USDC liability fill the 10 slots
+    let mut collateral = Map::new(&env);
+    for index in 0..9u32 {
+        collateral.set(index, 100_0000000i128);
+    }
+    let mut liabilities = Map::new(&env);
+    liabilities.set(9u32, 50_0000000i128);
+    pool_client.set_positions(&user, &collateral, &liabilities);
+    assert_eq!(client.get_pool_config().max_positions, 10);
+
+    // A new collateral asset would be the 11th position, dry run or not
+    let result = client.try_deposit_collateral(&user, &extra, &100_0000000);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::MaxPositionsExceeded);
+    client.set_dry_run(&admin, &true);
+    let result = client.try_deposit_collateral(&user, &extra, &100_0000000);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::MaxPositionsExceeded);
+    client.set_dry_run(&admin, &false);
+
+    // The limit follows the cached pool setting
+    let mut config = default_pool_config(&oracle);
+    config.max_positions = 11;
+    client.cache_pool_config(&admin, &config);
+    client.deposit_collateral(&user, &extra, &100_0000000);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+
+    // Topping up existing positions is still allowed
+    client.deposit_collateral(&user, &held, &100_0000000);
+    client.borrow(&user, &10_0000000);
+
+    // Opening the new position in a batch is rejected as well
+    let requests = vec![
+        &env,
+        Request {
+            request_type: RequestType::SupplyCollateral,
+            address: extra.clone(),
+            amount: 100_0000000,
+        },
+    ];
+    let result = client.try_submit(&user, &requests);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::MaxPositionsExceeded);
+}
+
+#[test]
+fn test_deposit_collateral_rolls_back_failed_submit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+    let xlm = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let xlm_client = token::Client::new(&env, &xlm);
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+    client.register_asset(&admin, &xlm, &0);
+    token::StellarAssetClient::new(&env, &xlm).mint(&user, &1000_0000000);
+
+    MockBlendPoolClient::new(&env, &blend_pool).set_failing(&true);
+
+    let result = client.try_deposit_collateral(&user, &xlm, &400_0000000);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::BlendOperationFailed);
+
+    // The failed call is rolled back: the user keeps the tokens and the
+    // pool holds no allowance
+    assert_eq!(xlm_client.balance(&user), 1000_0000000);
+    assert_eq!(xlm_client.balance(&contract_id), 0);
+    assert_eq!(xlm_client.allowance(&contract_id, &blend_pool), 0);
+}
+
+#[test]
+fn test_deposit_collateral_insufficient_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+    let xlm = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+    client.register_asset(&admin, &xlm, &0);
+    token::StellarAssetClient::new(&env, &xlm).mint(&user, &100_0000000);
+
+    let result = client.try_deposit_collateral(&user, &xlm, &400_0000000);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::InsufficientBalance);
+    assert_eq!(token::Client::new(&env, &xlm).balance(&user), 100_0000000);
+}
+
 #[test]
 fn test_deposit_collateral_unsupported_asset() {
     let env = Env::default();
@@ -166,7 +534,7 @@ fn test_deposit_collateral_unsupported_asset() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Try to deposit unsupported asset
-    let result = client.deposit_collateral(&user, &unsupported_asset, &1000);
+    let result = client.try_deposit_collateral(&user, &unsupported_asset, &1000);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::AssetNotSupported);
 }
@@ -187,10 +555,10 @@ fn test_withdraw_collateral_invalid_amount() {
     let xlm = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
-    client.register_asset(&admin, &xlm, &0).unwrap();
+    client.register_asset(&admin, &xlm, &0);
 
     // Test zero amount
-    let result = client.withdraw_collateral(&user, &xlm, &0);
+    let result = client.try_withdraw_collateral(&user, &xlm, &0);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 }
@@ -213,7 +581,7 @@ fn test_withdraw_collateral_unsupported_asset() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Try to withdraw unsupported asset
-    let result = client.withdraw_collateral(&user, &unsupported_asset, &1000);
+    let result = client.try_withdraw_collateral(&user, &unsupported_asset, &1000);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::AssetNotSupported);
 }
@@ -237,12 +605,12 @@ fn test_borrow_invalid_amount() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Test zero amount
-    let result = client.borrow(&user, &0);
+    let result = client.try_borrow(&user, &0);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 
     // Test negative amount
-    let result = client.borrow(&user, &-100);
+    let result = client.try_borrow(&user, &-100);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 }
@@ -264,12 +632,12 @@ fn test_repay_invalid_amount() {
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
     // Test zero amount
-    let result = client.repay(&user, &0);
+    let result = client.try_repay(&user, &0);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 
     // Test negative amount
-    let result = client.repay(&user, &-100);
+    let result = client.try_repay(&user, &-100);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidAmount);
 }
@@ -283,14 +651,14 @@ fn test_get_positions_empty() {
     let client = BlendAdapterContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    let blend_pool = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
     let oracle = Address::generate(&env);
     let usdc = Address::generate(&env);
     let user = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
-    let positions = client.get_positions(&user).unwrap();
+    let positions = client.get_positions(&user);
     assert!(positions.collateral.is_empty());
     assert!(positions.liabilities.is_empty());
     assert!(positions.supply.is_empty());
@@ -305,14 +673,14 @@ fn test_get_health_factor_no_positions() {
     let client = BlendAdapterContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    let blend_pool = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
     let oracle = Address::generate(&env);
     let usdc = Address::generate(&env);
     let user = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
-    let result = client.get_health_factor(&user).unwrap();
+    let result = client.get_health_factor(&user);
     // With no liabilities, health factor should be MAX
     assert_eq!(result.health_factor, i128::MAX);
     assert!(!result.is_liquidatable);
@@ -320,11 +688,62 @@ fn test_get_health_factor_no_positions() {
     assert_eq!(result.total_liabilities, 0);
 }
 
+#[test]
+fn test_health_factor_cache() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+    client.set_health_cache_ttl(&admin, &60);
+    assert_eq!(client.get_health_cache_ttl(), 60);
+
+    // Recomputing re-reads the Blend positions, which logs a get_pos event
+    let read_positions = |env: &Env| {
+        env.events().all().iter().any(|(contract, topics, _)| {
+            contract == contract_id
+                && topics == (symbol_short!("get_pos"), symbol_short!("start")).into_val(env)
+        })
+    };
+
+    client.get_health_factor(&user);
+    assert!(read_positions(&env));
+
+    // A second read within the TTL is served from the cache
+    env.ledger().with_mut(|li| li.timestamp += 30);
+    let result = client.get_health_factor(&user);
+    assert!(!read_positions(&env));
+    assert_eq!(result.health_factor, i128::MAX);
+
+    // A position change invalidates the cache
+    client.borrow(&user, &100_0000000);
+    client.get_health_factor(&user);
+    assert!(read_positions(&env));
+
+    // So does the TTL running out
+    client.get_health_factor(&user);
+    assert!(!read_positions(&env));
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    client.get_health_factor(&user);
+    assert!(read_positions(&env));
+}
+
 // ============ Pool Configuration Tests ============
 
 #[test]
 fn test_get_pool_config() {
     let env = Env::default();
+    env.mock_all_auths();
+
     let contract_id = env.register(BlendAdapterContract, ());
     let client = BlendAdapterContractClient::new(&env, &contract_id);
 
@@ -335,7 +754,18 @@ fn test_get_pool_config() {
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
-    let config = client.get_pool_config().unwrap();
+    // Nothing to serve until the admin mirrors the pool's config
+    let result = client.try_get_pool_config();
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::PoolConfigNotCached);
+
+    let mut config = default_pool_config(&oracle);
+    config.max_positions = 0;
+    let result = client.try_cache_pool_config(&admin, &config);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::InvalidParams);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+
+    let config = client.get_pool_config();
+    assert_eq!(config.oracle, oracle);
     assert_eq!(config.bstop_rate, 100);
     assert_eq!(config.status, 0);
     assert_eq!(config.max_positions, 10);
@@ -356,9 +786,9 @@ fn test_get_reserve() {
     let xlm = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
-    client.register_asset(&admin, &xlm, &0).unwrap();
+    client.register_asset(&admin, &xlm, &0);
 
-    let reserve = client.get_reserve(&xlm).unwrap();
+    let reserve = client.get_reserve(&xlm);
     assert_eq!(reserve.b_rate, 1_0000000);
     assert_eq!(reserve.d_rate, 1_0000000);
     assert_eq!(reserve.ir_mod, 1_0000000);
@@ -378,11 +808,59 @@ fn test_get_reserve_unsupported_asset() {
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
-    let result = client.get_reserve(&unsupported);
+    let result = client.try_get_reserve(&unsupported);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::AssetNotSupported);
 }
 
+#[test]
+fn test_get_reserve_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BlendAdapterContract, ());
+    let client = BlendAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let blend_pool = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let unsupported = Address::generate(&env);
+
+    client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.register_asset(&admin, &xlm, &0);
+
+    let result = client.try_get_reserve_config(&unsupported);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::AssetNotSupported);
+
+    let result = client.try_get_reserve_config(&xlm);
+    assert_eq!(result.unwrap_err().unwrap(), AdapterError::ReserveConfigNotCached);
+
+    let cached = ReserveConfig {
+        index: 0,
+        decimals: 7,
+        c_factor: 7500,
+        l_factor: 10000,
+        util: 8000,
+        max_util: 9500,
+        r_base: 100,
+        r_one: 400,
+        r_two: 2000,
+        r_three: 7500,
+        reactivity: 20,
+    };
+    client.cache_reserve_config(&admin, &xlm, &cached);
+
+    let config = client.get_reserve_config(&xlm);
+    assert_eq!(config.index, 0);
+    assert_eq!(config.c_factor, 7500);
+    assert_eq!(config.l_factor, 10000);
+    assert_eq!(config.util, 8000);
+    assert_eq!(config.r_one, 400);
+    assert_eq!(config.r_two, 2000);
+}
+
 #[test]
 fn test_get_reserve_list() {
     let env = Env::default();
@@ -396,7 +874,7 @@ fn test_get_reserve_list() {
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
-    let reserves = client.get_reserve_list().unwrap();
+    let reserves = client.get_reserve_list();
     assert!(reserves.is_empty());
 }
 
@@ -417,10 +895,10 @@ fn test_set_blend_pool() {
     let usdc = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
-    assert_eq!(client.blend_pool().unwrap(), blend_pool);
+    assert_eq!(client.blend_pool(), blend_pool);
 
-    client.set_blend_pool(&admin, &new_blend_pool).unwrap();
-    assert_eq!(client.blend_pool().unwrap(), new_blend_pool);
+    client.set_blend_pool(&admin, &new_blend_pool);
+    assert_eq!(client.blend_pool(), new_blend_pool);
 }
 
 #[test]
@@ -440,7 +918,7 @@ fn test_set_blend_pool_unauthorized() {
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
 
-    let result = client.set_blend_pool(&unauthorized, &new_blend_pool);
+    let result = client.try_set_blend_pool(&unauthorized, &new_blend_pool);
     assert!(result.is_err());
     assert_eq!(result.unwrap_err().unwrap(), AdapterError::Unauthorized);
 }
@@ -456,15 +934,16 @@ fn test_submit_empty_requests() {
     let client = BlendAdapterContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    let blend_pool = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
     let oracle = Address::generate(&env);
     let usdc = Address::generate(&env);
     let user = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
 
     let requests = Vec::new(&env);
-    let result = client.submit(&user, &requests);
+    let result = client.try_submit(&user, &requests);
     assert!(result.is_ok());
 }
 
@@ -477,14 +956,15 @@ fn test_submit_multiple_requests() {
     let client = BlendAdapterContractClient::new(&env, &contract_id);
 
     let admin = Address::generate(&env);
-    let blend_pool = Address::generate(&env);
+    let blend_pool = env.register(MockBlendPool, ());
     let oracle = Address::generate(&env);
     let usdc = Address::generate(&env);
     let user = Address::generate(&env);
     let xlm = Address::generate(&env);
 
     client.initialize(&admin, &blend_pool, &oracle, &usdc);
-    client.register_asset(&admin, &xlm, &0).unwrap();
+    client.cache_pool_config(&admin, &default_pool_config(&oracle));
+    client.register_asset(&admin, &xlm, &0);
 
     // Create multiple requests
     let request1 = Request {
@@ -502,6 +982,6 @@ fn test_submit_multiple_requests() {
     requests.push_back(request1);
     requests.push_back(request2);
 
-    let result = client.submit(&user, &requests);
+    let result = client.try_submit(&user, &requests);
     assert!(result.is_ok());
 }
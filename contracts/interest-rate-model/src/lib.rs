@@ -0,0 +1,158 @@
+#![no_std]
+
+//! Interest Rate Model Contract
+//!
+//! Standalone borrow rate model for the Vantis pool. The pool stores the
+//! address of a model contract and queries `rate(utilization)` whenever it
+//! needs the current borrow rate, so the curve can be replaced without
+//! redeploying the pool.
+//!
+//! ## Kink Model
+//! - Below optimal utilization: `base_rate + utilization * slope1 / optimal`
+//! - Above optimal utilization:
+//!   `base_rate + slope1 + (utilization - optimal) * slope2 / (100% - optimal)`
+//!
+//! All values are in basis points (10000 = 100%).
+
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env};
+
+/// Storage keys
+#[contracttype]
+pub enum DataKey {
+    /// Admin address
+    Admin,
+    /// Rate curve parameters
+    Params,
+}
+
+/// Kink model parameters
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RateModelParams {
+    /// Base interest rate (basis points per year)
+    pub base_rate: u32,
+    /// Slope 1: rate increase below optimal utilization
+    pub slope1: u32,
+    /// Slope 2: rate increase above optimal utilization
+    pub slope2: u32,
+    /// Optimal utilization (basis points)
+    pub optimal_utilization: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RateModelError {
+    /// Caller is not authorized
+    Unauthorized = 1,
+    /// Invalid model parameters
+    InvalidParams = 2,
+    /// Model has not been initialized
+    NotInitialized = 3,
+}
+
+#[contract]
+pub struct InterestRateModelContract;
+
+#[contractimpl]
+impl InterestRateModelContract {
+    /// Initialize the rate model
+    pub fn initialize(env: Env, admin: Address, params: RateModelParams) -> Result<(), RateModelError> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("Already initialized");
+        }
+
+        Self::validate_params(&params)?;
+
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Params, &params);
+
+        Ok(())
+    }
+
+    /// Get the borrow rate for a utilization
+    ///
+    /// # Arguments
+    /// * `utilization` - Pool utilization in basis points (10000 = 100%)
+    ///
+    /// # Returns
+    /// Annual borrow rate in basis points
+    pub fn rate(env: Env, utilization: u32) -> Result<u32, RateModelError> {
+        let params: RateModelParams = env
+            .storage()
+            .instance()
+            .get(&DataKey::Params)
+            .ok_or(RateModelError::NotInitialized)?;
+
+        Ok(Self::calculate_rate(&params, utilization.min(10000)))
+    }
+
+    /// Update the rate curve (admin only)
+    pub fn set_params(
+        env: Env,
+        caller: Address,
+        params: RateModelParams,
+    ) -> Result<(), RateModelError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        Self::validate_params(&params)?;
+
+        env.storage().instance().set(&DataKey::Params, &params);
+
+        env.events().publish(
+            (symbol_short!("params"), symbol_short!("updated")),
+            (params.base_rate, params.optimal_utilization),
+        );
+
+        Ok(())
+    }
+
+    // ============ View Functions ============
+
+    /// Get current rate curve parameters
+    pub fn get_params(env: Env) -> Result<RateModelParams, RateModelError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Params)
+            .ok_or(RateModelError::NotInitialized)
+    }
+
+    /// Get admin address
+    pub fn admin(env: Env) -> Result<Address, RateModelError> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(RateModelError::Unauthorized)
+    }
+
+    // ============ Internal Functions ============
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), RateModelError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *caller != admin {
+            return Err(RateModelError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn validate_params(params: &RateModelParams) -> Result<(), RateModelError> {
+        // Optimal utilization must leave room on both sides of the kink
+        if params.optimal_utilization == 0 || params.optimal_utilization >= 10000 {
+            return Err(RateModelError::InvalidParams);
+        }
+        Ok(())
+    }
+
+    fn calculate_rate(params: &RateModelParams, utilization: u32) -> u32 {
+        if utilization <= params.optimal_utilization {
+            params.base_rate + utilization * params.slope1 / params.optimal_utilization
+        } else {
+            let excess = utilization - params.optimal_utilization;
+            let remaining = 10000 - params.optimal_utilization;
+            params.base_rate + params.slope1 + excess * params.slope2 / remaining
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
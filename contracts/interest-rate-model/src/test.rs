@@ -0,0 +1,87 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{testutils::Address as _, Env};
+
+fn default_params() -> RateModelParams {
+    RateModelParams {
+        base_rate: 200,            // 2%
+        slope1: 400,               // 4%
+        slope2: 7500,              // 75%
+        optimal_utilization: 8000, // 80%
+    }
+}
+
+#[test]
+fn test_initialize() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(InterestRateModelContract, ());
+    let client = InterestRateModelContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_params());
+
+    assert_eq!(client.admin(), admin);
+    assert_eq!(client.get_params().optimal_utilization, 8000);
+}
+
+#[test]
+fn test_rate_kink() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(InterestRateModelContract, ());
+    let client = InterestRateModelContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin, &default_params());
+
+    // At 0% utilization: base rate
+    assert_eq!(client.rate(&0), 200);
+
+    // At 40% utilization: 2% + 40/80 * 4% = 4%
+    assert_eq!(client.rate(&4000), 400);
+
+    // At optimal: 2% + 4% = 6%
+    assert_eq!(client.rate(&8000), 600);
+
+    // At 90%: 6% + 10/20 * 75% = 43.5%
+    assert_eq!(client.rate(&9000), 4350);
+
+    // Utilization above 100% is clamped
+    assert_eq!(client.rate(&12000), client.rate(&10000));
+}
+
+#[test]
+fn test_set_params() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(InterestRateModelContract, ());
+    let client = InterestRateModelContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let unauthorized = Address::generate(&env);
+    client.initialize(&admin, &default_params());
+
+    let new_params = RateModelParams {
+        base_rate: 500,
+        ..default_params()
+    };
+
+    let result = client.try_set_params(&unauthorized, &new_params);
+    assert_eq!(result, Err(Ok(RateModelError::Unauthorized)));
+
+    client.set_params(&admin, &new_params);
+    assert_eq!(client.rate(&0), 500);
+
+    // Optimal utilization of 100% would divide by zero above the kink
+    let invalid = RateModelParams {
+        optimal_utilization: 10000,
+        ..default_params()
+    };
+    let result = client.try_set_params(&admin, &invalid);
+    assert_eq!(result, Err(Ok(RateModelError::InvalidParams)));
+}
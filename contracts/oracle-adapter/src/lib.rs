@@ -16,10 +16,13 @@
 //! - This format ensures precision for both high-value assets (BTC, ETH) and low-value assets
 //!
 //! ## Price Feed Characteristics
-//! - **Source**: Stellar's Reflector Oracle
+//! - **Source**: Stellar's Reflector Oracle, with an ordered fallback chain of
+//!   Reflector-compatible feeds before the cached price
 //! - **Decimal Precision**: 14 decimals (i128 type)
 //! - **Staleness Check**: Configurable threshold (default 300 seconds / 5 minutes)
-//! - **Volatility Tracking**: 7-day and 30-day historical volatility in basis points
+//! - **Volatility Tracking**: 7-day and 30-day historical volatility in basis points,
+//!   plus an exponentially-weighted (EWMA) volatility that favours recent returns.
+//!   Longer windows (e.g. 90-day) are available up to the configurable history capacity
 //!
 //! ## Integration with Blend
 //! The oracle adapter provides:
@@ -45,6 +48,11 @@ use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
 };
 
+mod reflector;
+
+pub use reflector::{ReflectorAsset, ReflectorPriceData};
+use reflector::ReflectorClient;
+
 /// Storage keys
 #[contracttype]
 pub enum DataKey {
@@ -60,6 +68,20 @@ pub enum DataKey {
     Assets,
     /// Price staleness threshold in seconds
     StalenessThreshold,
+    /// EWMA decay factor lambda (basis points, e.g., 9400 = 0.94)
+    EwmaLambda,
+    /// Ordered price sources tried before the cached price: Vec<Address>
+    OracleSources,
+    /// Minimum accepted price confidence (basis points)
+    MinConfidence,
+    /// Asset configurations: Map<asset_symbol, AssetConfig>
+    AssetConfigs,
+    /// Quarantined assets: Map<asset_symbol, bool>
+    AssetPaused,
+    /// Number of price points kept per asset for volatility
+    HistoryCapacity,
+    /// Addresses allowed to push prices: Map<Address, bool>
+    Keepers,
 }
 
 /// Price data structure
@@ -82,6 +104,8 @@ pub struct PriceData {
     pub timestamp: u64,
     /// Source identifier (e.g., "reflector")
     pub source: Symbol,
+    /// Confidence in the price (basis points, 10000 = full confidence)
+    pub confidence: u32,
 }
 
 /// Volatility data for risk calculations
@@ -93,9 +117,12 @@ pub struct VolatilityData {
     pub volatility_30d: u32,
     /// 7-day historical volatility
     pub volatility_7d: u32,
+    /// Exponentially-weighted volatility (annualized, in basis points)
+    /// Recent returns carry more weight than older ones
+    pub volatility_ewma: u32,
     /// Last update timestamp
     pub last_updated: u64,
-    /// Historical prices for volatility calculation (last 30 data points)
+    /// Historical prices for volatility calculation (up to the history capacity)
     pub price_history: Vec<i128>,
 }
 
@@ -113,6 +140,10 @@ pub struct AssetConfig {
     pub base_ltv: u32,
     /// Liquidation threshold (in basis points)
     pub liquidation_threshold: u32,
+    /// Maximum price age in seconds (0 = use the global threshold)
+    pub staleness_threshold: u64,
+    /// Decimals the pushed price feed reports in (normalized to 14 on ingest)
+    pub feed_decimals: u32,
 }
 
 #[contracterror]
@@ -131,8 +162,38 @@ pub enum OracleError {
     InvalidPrice = 5,
     /// Insufficient price history for volatility
     InsufficientHistory = 6,
+    /// Invalid configuration parameters
+    InvalidParams = 7,
+    /// Price confidence is below the configured minimum
+    LowConfidence = 8,
+    /// Asset is paused by the admin
+    AssetPaused = 9,
 }
 
+/// Default EWMA decay factor (0.94, the RiskMetrics daily standard)
+const DEFAULT_EWMA_LAMBDA: u32 = 9400;
+
+/// Default number of price points kept per asset
+const DEFAULT_HISTORY_CAPACITY: u32 = 30;
+
+/// Upper bound on the history capacity (one year of daily points)
+const MAX_HISTORY_CAPACITY: u32 = 365;
+
+/// Maximum number of live price sources (primary, secondary, tertiary)
+const MAX_ORACLE_SOURCES: u32 = 3;
+
+/// Price decimals used by the adapter (Blend standard)
+const PRICE_DECIMALS: u32 = 14;
+
+/// Largest feed precision accepted for an asset
+const MAX_FEED_DECIMALS: u32 = 18;
+
+/// Deviation `verify_price_scale` accepts from the expected price (10%)
+const PRICE_SCALE_TOLERANCE_BP: i128 = 1000;
+
+/// Factor from USD cents to the 14-decimal price format
+const CENTS_TO_PRICE: i128 = 1_000_000_000_000;
+
 #[contract]
 pub struct OracleAdapterContract;
 
@@ -147,14 +208,21 @@ impl OracleAdapterContract {
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::OracleContract, &oracle_contract);
         env.storage().instance().set(&DataKey::StalenessThreshold, &3600u64); // 1 hour default for testing
+        env.storage().instance().set(&DataKey::EwmaLambda, &DEFAULT_EWMA_LAMBDA);
         env.storage().instance().set(&DataKey::Assets, &Vec::<Symbol>::new(&env));
     }
 
     /// Add a supported asset
+    ///
+    /// Errors with `InvalidParams` if `feed_decimals` exceeds 18.
     pub fn add_asset(env: Env, caller: Address, config: AssetConfig) -> Result<(), OracleError> {
         caller.require_auth();
         Self::require_admin(&env, &caller)?;
 
+        if config.feed_decimals > MAX_FEED_DECIMALS {
+            return Err(OracleError::InvalidParams);
+        }
+
         let mut assets: Vec<Symbol> = env
             .storage()
             .instance()
@@ -164,10 +232,16 @@ impl OracleAdapterContract {
         assets.push_back(config.symbol.clone());
         env.storage().instance().set(&DataKey::Assets, &assets);
 
+        env.storage().persistent().set(
+            &(DataKey::AssetConfigs, config.symbol.clone()),
+            &config,
+        );
+
         // Initialize volatility data
         let volatility = VolatilityData {
             volatility_30d: 0,
             volatility_7d: 0,
+            volatility_ewma: 0,
             last_updated: 0,
             price_history: Vec::new(&env),
         };
@@ -198,15 +272,26 @@ impl OracleAdapterContract {
     /// For an asset priced at $0.10:
     /// - Returns: 10_000_000_000_000 (10^13)
     ///
+    /// # Fallback Chain
+    /// Each configured oracle source is queried in order and the first fresh,
+    /// non-zero price is returned with `source` set to `primary`, `secondary`
+    /// or `tertiary`. If every source misses, the cached price is used.
+    ///
     /// # Errors
     /// - `AssetNotSupported`: Asset is not registered
     /// - `InvalidPrice`: No price data available
     /// - `StalePrice`: Price is older than staleness threshold
+    /// - `LowConfidence`: Cached price confidence is below the minimum
+    /// - `AssetPaused`: Asset is quarantined
     pub fn get_price(env: Env, asset: Symbol) -> Result<PriceData, OracleError> {
         Self::require_asset_supported(&env, &asset)?;
+        Self::require_asset_active(&env, &asset)?;
+
+        if let Some(data) = Self::query_sources(&env, &asset) {
+            return Ok(data);
+        }
 
-        // In production, this would call the Reflector oracle
-        // For now, return cached price or fetch from oracle
+        // Fall back to the last pushed price
         let price_data: Option<PriceData> = env
             .storage()
             .persistent()
@@ -215,62 +300,94 @@ impl OracleAdapterContract {
         match price_data {
             Some(data) => {
                 // Check staleness
-                let threshold: u64 = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::StalenessThreshold)
-                    .unwrap_or(300);
+                let threshold = Self::staleness_threshold(&env, &asset);
 
                 let current_time = env.ledger().timestamp();
                 if current_time - data.timestamp > threshold {
                     return Err(OracleError::StalePrice);
                 }
 
+                if data.confidence < Self::get_min_confidence(env.clone()) {
+                    return Err(OracleError::LowConfidence);
+                }
+
                 Ok(data)
             }
             None => Err(OracleError::InvalidPrice),
         }
     }
 
+    /// Get the last pushed price without staleness or confidence checks
+    ///
+    /// For read-only views that would rather show an old price than fail;
+    /// never use it to gate a state change.
+    ///
+    /// # Errors
+    /// - `AssetNotSupported`: Asset is not registered
+    /// - `InvalidPrice`: No price has been pushed
+    pub fn get_last_price(env: Env, asset: Symbol) -> Result<PriceData, OracleError> {
+        Self::require_asset_supported(&env, &asset)?;
+
+        env.storage()
+            .persistent()
+            .get(&(DataKey::Prices, asset))
+            .ok_or(OracleError::InvalidPrice)
+    }
+
     /// Update price from oracle (called by keeper or oracle push)
     ///
     /// # Blend Compatibility
-    /// Prices must be provided in 14-decimal format as required by Blend Protocol.
-    /// This function validates and stores prices in the exact format needed for
-    /// Blend's oracle interface.
+    /// Prices are provided in the asset's `feed_decimals` and normalized to the
+    /// 14-decimal format required by Blend Protocol before being stored, so
+    /// 7- or 8-decimal feeds can be pushed as reported.
     ///
     /// # Arguments
     /// * `caller` - Address authorized to update prices (typically oracle keeper)
     /// * `asset` - Asset symbol to update
-    /// * `price` - Price in USD with 14 decimals (Blend format)
+    /// * `price` - Price in USD with the asset's `feed_decimals`
+    /// * `confidence` - Confidence in the price (basis points, 10000 = full)
     ///
     /// # Example
-    /// To set price of $0.10:
+    /// To set price of $0.10 with full confidence:
     /// ```ignore
-    /// update_price(env, caller, symbol_short!("XLM"), 10_000_000_000_000)
+    /// update_price(env, caller, symbol_short!("XLM"), 10_000_000_000_000, 10000)
     /// ```
     ///
     /// # Errors
+    /// - `Unauthorized`: Caller is neither the admin nor an allowlisted keeper
     /// - `AssetNotSupported`: Asset is not registered
     /// - `InvalidPrice`: Price is <= 0
+    /// - `InvalidParams`: Confidence exceeds 10000
     pub fn update_price(
         env: Env,
         caller: Address,
         asset: Symbol,
         price: i128,
+        confidence: u32,
     ) -> Result<(), OracleError> {
         caller.require_auth();
+        Self::require_keeper(&env, &caller)?;
         Self::require_asset_supported(&env, &asset)?;
 
         if price <= 0 {
             return Err(OracleError::InvalidPrice);
         }
 
+        if confidence > 10000 {
+            return Err(OracleError::InvalidParams);
+        }
+
+        let price = Self::scale_price(&env, &asset, price)?;
+        if price <= 0 {
+            return Err(OracleError::InvalidPrice);
+        }
+
         let timestamp = env.ledger().timestamp();
         let price_data = PriceData {
             price,
             timestamp,
             source: symbol_short!("reflector"),
+            confidence,
         };
 
         // Store price in persistent storage
@@ -288,8 +405,11 @@ impl OracleAdapterContract {
     }
 
     /// Get volatility data for an asset
+    ///
+    /// Errors with `AssetPaused` while the asset is quarantined.
     pub fn get_volatility(env: Env, asset: Symbol) -> Result<VolatilityData, OracleError> {
         Self::require_asset_supported(&env, &asset)?;
+        Self::require_asset_active(&env, &asset)?;
 
         env.storage()
             .persistent()
@@ -297,6 +417,85 @@ impl OracleAdapterContract {
             .ok_or(OracleError::InsufficientHistory)
     }
 
+    /// Historical volatility over the last `period` price points
+    ///
+    /// Unlike the 7- and 30-point figures in `VolatilityData`, this is
+    /// computed on demand, so windows up to the history capacity (e.g. 90
+    /// days) are available.
+    ///
+    /// # Errors
+    /// - `InvalidParams`: `period` is below 2 or above the history capacity
+    /// - `InsufficientHistory`: Fewer than `period` points have been recorded
+    pub fn get_historical_volatility(
+        env: Env,
+        asset: Symbol,
+        period: u32,
+    ) -> Result<u32, OracleError> {
+        Self::require_asset_supported(&env, &asset)?;
+        Self::require_asset_active(&env, &asset)?;
+
+        if period < 2 || period > Self::get_history_capacity(env.clone()) {
+            return Err(OracleError::InvalidParams);
+        }
+
+        let volatility_data: VolatilityData = env
+            .storage()
+            .persistent()
+            .get(&(DataKey::Volatility, asset))
+            .ok_or(OracleError::InsufficientHistory)?;
+
+        if volatility_data.price_history.len() < period {
+            return Err(OracleError::InsufficientHistory);
+        }
+
+        Ok(Self::calculate_volatility(&volatility_data.price_history, period))
+    }
+
+    /// Get the configuration of a supported asset
+    pub fn get_asset_config(env: Env, asset: Symbol) -> Result<AssetConfig, OracleError> {
+        Self::require_asset_supported(&env, &asset)?;
+
+        env.storage()
+            .persistent()
+            .get(&(DataKey::AssetConfigs, asset))
+            .ok_or(OracleError::AssetNotSupported)
+    }
+
+    /// Check the stored price against an expected USD value (admin only)
+    ///
+    /// A post-deployment self-test for newly wired assets: the cached
+    /// 14-decimal price must be within 10% of `expected_usd_cents`, which
+    /// catches feeds whose decimals are misconfigured by a power of ten.
+    ///
+    /// # Errors
+    /// - `InvalidParams`: `expected_usd_cents` is not positive
+    /// - `InvalidPrice`: No price has been pushed for the asset
+    pub fn verify_price_scale(
+        env: Env,
+        caller: Address,
+        asset: Symbol,
+        expected_usd_cents: i128,
+    ) -> Result<bool, OracleError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        Self::require_asset_supported(&env, &asset)?;
+
+        if expected_usd_cents <= 0 {
+            return Err(OracleError::InvalidParams);
+        }
+
+        let price_data: PriceData = env
+            .storage()
+            .persistent()
+            .get(&(DataKey::Prices, asset))
+            .ok_or(OracleError::InvalidPrice)?;
+
+        let expected = expected_usd_cents.saturating_mul(CENTS_TO_PRICE);
+        let deviation = (price_data.price - expected).abs();
+
+        Ok(deviation.saturating_mul(10000) <= expected.saturating_mul(PRICE_SCALE_TOLERANCE_BP))
+    }
+
     /// Calculate the safe borrow amount based on volatility-adjusted LTV
     /// Formula: B_safe = V_collateral × (LTV_base - k × σ × √T)
     ///
@@ -308,7 +507,8 @@ impl OracleAdapterContract {
     /// * `time_horizon_days` - Time horizon for volatility adjustment
     ///
     /// # Returns
-    /// * Safe borrow amount in USD (14 decimals)
+    /// * Safe borrow amount in USD (14 decimals), 0 if the adjustment exceeds
+    ///   the base LTV and the asset is too volatile to borrow against
     pub fn calculate_safe_borrow(
         env: Env,
         asset: Symbol,
@@ -330,11 +530,14 @@ impl OracleAdapterContract {
         // Adjusted LTV = LTV_base - k × σ × √T
         // All in basis points (10000 = 100%)
         let adjustment = (k_factor as i128 * sigma * sqrt_t) / (1000 * 10000);
-        let adjusted_ltv = (base_ltv as i128).saturating_sub(adjustment);
+        let adjusted_ltv = base_ltv as i128 - adjustment;
 
-        // Ensure LTV doesn't go below a minimum threshold (e.g., 30%)
+        // Ensure LTV doesn't go below a minimum threshold (e.g., 30%), unless
+        // volatility wipes out the base LTV entirely: then nothing is borrowable
         let min_ltv: i128 = 3000; // 30%
-        let final_ltv = if adjusted_ltv < min_ltv {
+        let final_ltv = if adjusted_ltv < 0 {
+            0
+        } else if adjusted_ltv < min_ltv {
             min_ltv
         } else {
             adjusted_ltv
@@ -362,8 +565,167 @@ impl OracleAdapterContract {
         Ok(())
     }
 
+    /// Set the minimum confidence a cached price needs to be served
+    ///
+    /// # Arguments
+    /// * `min_confidence` - Minimum confidence in basis points (0 disables the check)
+    pub fn set_min_confidence(
+        env: Env,
+        caller: Address,
+        min_confidence: u32,
+    ) -> Result<(), OracleError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if min_confidence > 10000 {
+            return Err(OracleError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinConfidence, &min_confidence);
+
+        Ok(())
+    }
+
+    /// Quarantine a single asset (admin only)
+    ///
+    /// `get_price`, `get_volatility` and `calculate_safe_borrow` reject the
+    /// asset with `AssetPaused` until it is unpaused. Other assets are
+    /// unaffected.
+    pub fn pause_asset(env: Env, caller: Address, asset: Symbol) -> Result<(), OracleError> {
+        Self::set_asset_paused(&env, &caller, asset, true)
+    }
+
+    /// Lift the quarantine on an asset (admin only)
+    pub fn unpause_asset(env: Env, caller: Address, asset: Symbol) -> Result<(), OracleError> {
+        Self::set_asset_paused(&env, &caller, asset, false)
+    }
+
+    /// Check whether an asset is quarantined
+    pub fn is_asset_paused(env: Env, asset: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(DataKey::AssetPaused, asset))
+            .unwrap_or(false)
+    }
+
+    /// Allow an address to push prices via `update_price` (admin only)
+    ///
+    /// The admin can always push prices; keepers are a separate, revocable
+    /// role for automated feeds.
+    pub fn add_keeper(env: Env, caller: Address, keeper: Address) -> Result<(), OracleError> {
+        Self::set_keeper(&env, &caller, keeper, true)
+    }
+
+    /// Revoke a keeper's permission to push prices (admin only)
+    pub fn remove_keeper(env: Env, caller: Address, keeper: Address) -> Result<(), OracleError> {
+        Self::set_keeper(&env, &caller, keeper, false)
+    }
+
+    /// Check whether an address is an allowlisted keeper
+    pub fn is_keeper(env: Env, keeper: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(DataKey::Keepers, keeper))
+            .unwrap_or(false)
+    }
+
+    /// Get the minimum accepted price confidence (basis points)
+    pub fn get_min_confidence(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinConfidence)
+            .unwrap_or(0)
+    }
+
+    /// Set how many price points are kept per asset (admin only)
+    ///
+    /// Must be between 30 (needed for `volatility_30d`) and 365. Each point
+    /// is stored in the asset's persistent `VolatilityData`, so larger values
+    /// raise storage costs. Lowering the capacity trims histories on their
+    /// next update.
+    pub fn set_history_capacity(
+        env: Env,
+        caller: Address,
+        capacity: u32,
+    ) -> Result<(), OracleError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if !(DEFAULT_HISTORY_CAPACITY..=MAX_HISTORY_CAPACITY).contains(&capacity) {
+            return Err(OracleError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::HistoryCapacity, &capacity);
+
+        Ok(())
+    }
+
+    /// Get the number of price points kept per asset
+    pub fn get_history_capacity(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HistoryCapacity)
+            .unwrap_or(DEFAULT_HISTORY_CAPACITY)
+    }
+
+    /// Set the EWMA decay factor used for `volatility_ewma`
+    ///
+    /// # Arguments
+    /// * `lambda` - Decay factor in basis points (0 < lambda < 10000).
+    ///   Lower values react faster to recent returns.
+    pub fn set_ewma_lambda(env: Env, caller: Address, lambda: u32) -> Result<(), OracleError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if lambda == 0 || lambda >= 10000 {
+            return Err(OracleError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::EwmaLambda, &lambda);
+
+        Ok(())
+    }
+
+    /// Set the ordered list of live price sources (admin only)
+    ///
+    /// Sources must implement the Reflector `lastprice`/`decimals` interface.
+    /// At most three sources are allowed; an empty list disables live queries.
+    pub fn set_oracle_sources(
+        env: Env,
+        caller: Address,
+        sources: Vec<Address>,
+    ) -> Result<(), OracleError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if sources.len() > MAX_ORACLE_SOURCES {
+            return Err(OracleError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::OracleSources, &sources);
+
+        env.events().publish(
+            (symbol_short!("sources"), symbol_short!("updated")),
+            sources.len(),
+        );
+
+        Ok(())
+    }
+
     // ============ View Functions ============
 
+    /// Get the ordered list of live price sources
+    pub fn get_oracle_sources(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::OracleSources)
+            .unwrap_or(Vec::new(&env))
+    }
+
     /// Get admin address
     pub fn admin(env: Env) -> Result<Address, OracleError> {
         env.storage()
@@ -398,12 +760,72 @@ impl OracleAdapterContract {
 
     // ============ Internal Functions ============
 
+    /// Query each configured source in order for a fresh, non-zero price
+    ///
+    /// Sources that error, return nothing, or return a stale or non-positive
+    /// price are skipped.
+    fn query_sources(env: &Env, asset: &Symbol) -> Option<PriceData> {
+        let sources: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::OracleSources)
+            .unwrap_or(Vec::new(env));
+
+        let threshold = Self::staleness_threshold(env, asset);
+        let current_time = env.ledger().timestamp();
+
+        for (index, source) in sources.iter().enumerate() {
+            let client = ReflectorClient::new(env, &source);
+
+            let record = match client.try_lastprice(&ReflectorAsset::Other(asset.clone())) {
+                Ok(Ok(Some(record))) => record,
+                _ => continue,
+            };
+
+            if record.price <= 0 || current_time.saturating_sub(record.timestamp) > threshold {
+                continue;
+            }
+
+            let decimals = match client.try_decimals() {
+                Ok(Ok(decimals)) => decimals,
+                _ => continue,
+            };
+
+            let source_name = match index {
+                0 => symbol_short!("primary"),
+                1 => symbol_short!("secondary"),
+                _ => symbol_short!("tertiary"),
+            };
+
+            return Some(PriceData {
+                price: Self::convert_price_decimals(record.price, decimals, PRICE_DECIMALS),
+                timestamp: record.timestamp,
+                source: source_name,
+                // Live feeds do not report confidence; a fresh quote is trusted
+                confidence: 10000,
+            });
+        }
+
+        None
+    }
+
+    /// Normalize a price pushed in the asset's feed decimals to 14 decimals
+    fn scale_price(env: &Env, asset: &Symbol, price: i128) -> Result<i128, OracleError> {
+        let config: AssetConfig = env
+            .storage()
+            .persistent()
+            .get(&(DataKey::AssetConfigs, asset.clone()))
+            .ok_or(OracleError::AssetNotSupported)?;
+
+        Ok(Self::convert_price_decimals(price, config.feed_decimals, PRICE_DECIMALS))
+    }
+
     /// Convert price from one decimal format to another
     ///
     /// # Blend Compatibility
     /// This helper function can convert prices from other decimal formats to
-    /// Blend's required 14-decimal format. However, the oracle adapter always
-    /// works with 14-decimal prices internally.
+    /// Blend's required 14-decimal format. The oracle adapter always works
+    /// with 14-decimal prices internally.
     ///
     /// # Arguments
     /// * `price` - Price value
@@ -420,7 +842,6 @@ impl OracleAdapterContract {
     /// let price_14d = Self::convert_price_decimals(price_8d, 8, 14);
     /// // Result: 10_000_000_000_000 (same value, 14 decimals)
     /// ```
-    #[allow(dead_code)]
     fn convert_price_decimals(price: i128, from_decimals: u32, to_decimals: u32) -> i128 {
         if from_decimals == to_decimals {
             return price;
@@ -437,6 +858,69 @@ impl OracleAdapterContract {
         }
     }
 
+    fn set_asset_paused(
+        env: &Env,
+        caller: &Address,
+        asset: Symbol,
+        paused: bool,
+    ) -> Result<(), OracleError> {
+        caller.require_auth();
+        Self::require_admin(env, caller)?;
+        Self::require_asset_supported(env, &asset)?;
+
+        let key = (DataKey::AssetPaused, asset.clone());
+        if paused {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        env.events().publish(
+            (symbol_short!("asset"), symbol_short!("paused")),
+            (asset, paused),
+        );
+
+        Ok(())
+    }
+
+    fn set_keeper(
+        env: &Env,
+        caller: &Address,
+        keeper: Address,
+        allowed: bool,
+    ) -> Result<(), OracleError> {
+        caller.require_auth();
+        Self::require_admin(env, caller)?;
+
+        let key = (DataKey::Keepers, keeper.clone());
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("set")),
+            (keeper, allowed),
+        );
+
+        Ok(())
+    }
+
+    fn require_keeper(env: &Env, caller: &Address) -> Result<(), OracleError> {
+        if Self::is_keeper(env.clone(), caller.clone()) {
+            return Ok(());
+        }
+        Self::require_admin(env, caller)
+    }
+
+    fn require_asset_active(env: &Env, asset: &Symbol) -> Result<(), OracleError> {
+        if Self::is_asset_paused(env.clone(), asset.clone()) {
+            return Err(OracleError::AssetPaused);
+        }
+        Ok(())
+    }
+
     fn require_admin(env: &Env, caller: &Address) -> Result<(), OracleError> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         if *caller != admin {
@@ -445,6 +929,23 @@ impl OracleAdapterContract {
         Ok(())
     }
 
+    /// Staleness threshold for an asset, falling back to the global value
+    fn staleness_threshold(env: &Env, asset: &Symbol) -> u64 {
+        let config: Option<AssetConfig> = env
+            .storage()
+            .persistent()
+            .get(&(DataKey::AssetConfigs, asset.clone()));
+
+        match config {
+            Some(config) if config.staleness_threshold > 0 => config.staleness_threshold,
+            _ => env
+                .storage()
+                .instance()
+                .get(&DataKey::StalenessThreshold)
+                .unwrap_or(300),
+        }
+    }
+
     fn require_asset_supported(env: &Env, asset: &Symbol) -> Result<(), OracleError> {
         if !Self::is_asset_supported(env.clone(), asset.clone()) {
             return Err(OracleError::AssetNotSupported);
@@ -460,6 +961,7 @@ impl OracleAdapterContract {
             .unwrap_or(VolatilityData {
                 volatility_30d: 0,
                 volatility_7d: 0,
+                volatility_ewma: 0,
                 last_updated: 0,
                 price_history: Vec::new(env),
             });
@@ -467,14 +969,23 @@ impl OracleAdapterContract {
         // Add new price to history
         volatility_data.price_history.push_back(price);
 
-        // Keep only last 30 data points
-        while volatility_data.price_history.len() > 30 {
+        // Keep only the last `capacity` data points
+        let capacity = Self::get_history_capacity(env.clone());
+        while volatility_data.price_history.len() > capacity {
             volatility_data.price_history.pop_front();
         }
 
         // Calculate volatility if we have enough data
         if volatility_data.price_history.len() >= 7 {
             volatility_data.volatility_7d = Self::calculate_volatility(&volatility_data.price_history, 7);
+
+            let lambda: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::EwmaLambda)
+                .unwrap_or(DEFAULT_EWMA_LAMBDA);
+            volatility_data.volatility_ewma =
+                Self::calculate_ewma_volatility(&volatility_data.price_history, lambda);
         }
         if volatility_data.price_history.len() >= 30 {
             volatility_data.volatility_30d = Self::calculate_volatility(&volatility_data.price_history, 30);
@@ -539,6 +1050,46 @@ impl OracleAdapterContract {
         annualized as u32
     }
 
+    /// Calculate exponentially-weighted volatility from price history
+    ///
+    /// Walks returns oldest to newest applying
+    /// `var = lambda * var + (1 - lambda) * r^2`, so each older return's
+    /// weight decays by `lambda` per step. The result is normalized by the
+    /// total weight so a short history is not biased toward zero.
+    /// Returns annualized volatility in basis points
+    fn calculate_ewma_volatility(prices: &Vec<i128>, lambda: u32) -> u32 {
+        if prices.len() < 2 {
+            return 0;
+        }
+
+        let lambda = lambda as i128;
+        let mut weighted_sum: i128 = 0;
+        // Sum of applied weights (basis points)
+        let mut weight_total: i128 = 0;
+
+        for i in 1..prices.len() {
+            let prev = prices.get(i - 1).unwrap();
+            let curr = prices.get(i).unwrap();
+            if prev <= 0 {
+                continue;
+            }
+
+            // Return in basis points
+            let r = (curr - prev) * 10000 / prev;
+            weighted_sum = (lambda * weighted_sum + (10000 - lambda) * r * r) / 10000;
+            weight_total = (lambda * weight_total + (10000 - lambda) * 10000) / 10000;
+        }
+
+        if weight_total == 0 {
+            return 0;
+        }
+
+        let variance = weighted_sum * 10000 / weight_total;
+
+        // Annualize: multiply by sqrt(365) ≈ 19
+        (Self::integer_sqrt(variance) * 19) as u32
+    }
+
     /// Integer square root using Newton's method
     fn integer_sqrt(n: i128) -> i128 {
         if n <= 0 {
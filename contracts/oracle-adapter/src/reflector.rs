@@ -0,0 +1,36 @@
+//! Reflector-compatible price source interface
+//!
+//! Each configured oracle source is expected to implement the subset of the
+//! Reflector (SEP-40) interface declared here.
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+/// Asset identifier used by Reflector feeds
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum ReflectorAsset {
+    /// Stellar asset contract
+    Stellar(Address),
+    /// Off-chain asset identified by symbol
+    Other(Symbol),
+}
+
+/// Price record returned by a Reflector feed
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct ReflectorPriceData {
+    /// Price scaled by the feed's `decimals()`
+    pub price: i128,
+    /// Timestamp of the price record
+    pub timestamp: u64,
+}
+
+/// Subset of the Reflector interface used by the adapter
+///
+/// Only the generated `ReflectorClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "ReflectorClient")]
+pub trait ReflectorInterface {
+    fn lastprice(env: Env, asset: ReflectorAsset) -> Option<ReflectorPriceData>;
+    fn decimals(env: Env) -> u32;
+}
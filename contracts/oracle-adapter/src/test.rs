@@ -1,7 +1,35 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Env};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Env,
+};
+
+/// Mock Reflector feed; returns no price until one is set
+#[contract]
+pub struct MockReflector;
+
+#[contractimpl]
+impl MockReflector {
+    pub fn set_price(env: Env, price: i128, decimals: u32) {
+        let record = ReflectorPriceData {
+            price,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&symbol_short!("price"), &record);
+        env.storage().instance().set(&symbol_short!("decimals"), &decimals);
+    }
+
+    pub fn lastprice(env: Env, _asset: ReflectorAsset) -> Option<ReflectorPriceData> {
+        env.storage().instance().get(&symbol_short!("price"))
+    }
+
+    pub fn decimals(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("decimals")).unwrap_or(14)
+    }
+}
 
 // ============ Blend Compatibility Tests ============
 // These tests verify that the Oracle Adapter provides prices in the correct
@@ -42,6 +70,8 @@ fn test_add_asset() {
         decimals: 7,
         base_ltv: 7500,               // 75%
         liquidation_threshold: 8000,  // 80%
+        staleness_threshold: 0,       // global default
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
@@ -52,6 +82,44 @@ fn test_add_asset() {
     assert_eq!(assets.len(), 1);
 }
 
+#[test]
+fn test_get_asset_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let token = Address::generate(&env);
+    let config = AssetConfig {
+        symbol: symbol_short!("BTC"),
+        contract: token.clone(),
+        decimals: 8,
+        base_ltv: 7000,
+        liquidation_threshold: 7800,
+        staleness_threshold: 600,
+        feed_decimals: 14,
+    };
+
+    client.add_asset(&admin, &config);
+
+    let stored = client.get_asset_config(&symbol_short!("BTC"));
+    assert_eq!(stored.symbol, symbol_short!("BTC"));
+    assert_eq!(stored.contract, token);
+    assert_eq!(stored.decimals, 8);
+    assert_eq!(stored.base_ltv, 7000);
+    assert_eq!(stored.liquidation_threshold, 7800);
+    assert_eq!(stored.staleness_threshold, 600);
+
+    let result = client.try_get_asset_config(&symbol_short!("ETH"));
+    assert_eq!(result.err(), Some(Ok(OracleError::AssetNotSupported)));
+}
+
 #[test]
 fn test_update_and_get_price() {
     let env = Env::default();
@@ -71,18 +139,278 @@ fn test_update_and_get_price() {
         decimals: 7,
         base_ltv: 7500,
         liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
 
     // Update price: $0.10 with 14 decimals = 10_000_000_000_000
     let price = 10_000_000_000_000i128;
-    client.update_price(&admin, &symbol_short!("XLM"), &price);
+    client.update_price(&admin, &symbol_short!("XLM"), &price, &10000);
 
     let price_data = client.get_price(&symbol_short!("XLM"));
     assert_eq!(price_data.price, price);
 }
 
+#[test]
+fn test_update_price_requires_keeper() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let config = AssetConfig {
+        symbol: symbol_short!("XLM"),
+        contract: Address::generate(&env),
+        decimals: 7,
+        base_ltv: 7500,
+        liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
+    };
+    client.add_asset(&admin, &config);
+
+    let price = 10_000_000_000_000i128;
+    let result = client.try_update_price(&keeper, &symbol_short!("XLM"), &price, &10000);
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+
+    // Only the admin manages the allowlist
+    let result = client.try_add_keeper(&keeper, &keeper);
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+
+    client.add_keeper(&admin, &keeper);
+    assert!(client.is_keeper(&keeper));
+    client.update_price(&keeper, &symbol_short!("XLM"), &price, &10000);
+    assert_eq!(client.get_price(&symbol_short!("XLM")).price, price);
+
+    client.remove_keeper(&admin, &keeper);
+    assert!(!client.is_keeper(&keeper));
+    let result = client.try_update_price(&keeper, &symbol_short!("XLM"), &price, &10000);
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+}
+
+#[test]
+fn test_verify_price_scale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    for (symbol, feed_decimals) in [(symbol_short!("XLM"), 14), (symbol_short!("BTC"), 14)] {
+        client.add_asset(
+            &admin,
+            &AssetConfig {
+                symbol,
+                contract: Address::generate(&env),
+                decimals: 7,
+                base_ltv: 7500,
+                liquidation_threshold: 8000,
+                staleness_threshold: 0,
+                feed_decimals,
+            },
+        );
+    }
+
+    let result = client.try_verify_price_scale(&admin, &symbol_short!("XLM"), &12);
+    assert_eq!(result, Err(Ok(OracleError::InvalidPrice)));
+
+    // XLM pushed correctly at $0.12; BTC pushed 10x too large for $60,000
+    client.update_price(&admin, &symbol_short!("XLM"), &12_000_000_000_000, &10000);
+    client.update_price(&admin, &symbol_short!("BTC"), &60_000_000_000_000_000_000, &10000);
+
+    assert!(client.verify_price_scale(&admin, &symbol_short!("XLM"), &12));
+    assert!(!client.verify_price_scale(&admin, &symbol_short!("BTC"), &6_000_000));
+    assert!(!client.verify_price_scale(&admin, &symbol_short!("XLM"), &120));
+
+    let result = client.try_verify_price_scale(&oracle, &symbol_short!("XLM"), &12);
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+}
+
+#[test]
+fn test_get_last_price_ignores_staleness() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+    client.add_asset(
+        &admin,
+        &AssetConfig {
+            symbol: symbol_short!("XLM"),
+            contract: Address::generate(&env),
+            decimals: 7,
+            base_ltv: 7500,
+            liquidation_threshold: 8000,
+            staleness_threshold: 600,
+            feed_decimals: 14,
+        },
+    );
+
+    let result = client.try_get_last_price(&symbol_short!("XLM"));
+    assert_eq!(result.err(), Some(Ok(OracleError::InvalidPrice)));
+
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &10000);
+    env.ledger().with_mut(|li| li.timestamp += 601);
+
+    let result = client.try_get_price(&symbol_short!("XLM"));
+    assert_eq!(result.err(), Some(Ok(OracleError::StalePrice)));
+    assert_eq!(client.get_last_price(&symbol_short!("XLM")).price, 10_000_000_000_000);
+}
+
+#[test]
+fn test_update_price_normalizes_feed_decimals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let mut config = AssetConfig {
+        symbol: symbol_short!("BTC"),
+        contract: Address::generate(&env),
+        decimals: 8,
+        base_ltv: 7000,
+        liquidation_threshold: 7800,
+        staleness_threshold: 0,
+        feed_decimals: 19,
+    };
+
+    let result = client.try_add_asset(&admin, &config);
+    assert_eq!(result, Err(Ok(OracleError::InvalidParams)));
+
+    config.feed_decimals = 8;
+    client.add_asset(&admin, &config);
+
+    // $60,000.12345678 as reported by an 8-decimal feed
+    client.update_price(&admin, &symbol_short!("BTC"), &6_000_012_345_678, &10000);
+
+    let price_data = client.get_price(&symbol_short!("BTC"));
+    assert_eq!(price_data.price, 6_000_012_345_678_000_000);
+
+    let history = client.get_volatility(&symbol_short!("BTC")).price_history;
+    assert_eq!(history.get(0), Some(6_000_012_345_678_000_000));
+}
+
+#[test]
+fn test_price_source_fallback_chain() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let config = AssetConfig {
+        symbol: symbol_short!("XLM"),
+        contract: Address::generate(&env),
+        decimals: 7,
+        base_ltv: 7500,
+        liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
+    };
+
+    client.add_asset(&admin, &config);
+
+    // Cached price: $0.10
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &10000);
+
+    // Primary has no price; secondary reports $0.12 with 7 decimals
+    let primary = env.register(MockReflector, ());
+    let secondary = env.register(MockReflector, ());
+    MockReflectorClient::new(&env, &secondary).set_price(&1_200_000, &7);
+
+    let sources = vec![&env, primary.clone(), secondary.clone()];
+    client.set_oracle_sources(&admin, &sources);
+    assert_eq!(client.get_oracle_sources(), sources);
+
+    let price_data = client.get_price(&symbol_short!("XLM"));
+    assert_eq!(price_data.price, 12_000_000_000_000);
+    assert_eq!(price_data.source, symbol_short!("secondary"));
+
+    // Without live sources the cached price is used
+    client.set_oracle_sources(&admin, &vec![&env, primary.clone()]);
+    let price_data = client.get_price(&symbol_short!("XLM"));
+    assert_eq!(price_data.price, 10_000_000_000_000);
+    assert_eq!(price_data.source, symbol_short!("reflector"));
+
+    // At most three sources
+    let too_many = vec![&env, primary.clone(), primary.clone(), secondary.clone(), secondary];
+    let result = client.try_set_oracle_sources(&admin, &too_many);
+    assert_eq!(result, Err(Ok(OracleError::InvalidParams)));
+}
+
+#[test]
+fn test_low_confidence_price_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let config = AssetConfig {
+        symbol: symbol_short!("XLM"),
+        contract: Address::generate(&env),
+        decimals: 7,
+        base_ltv: 7500,
+        liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
+    };
+
+    client.add_asset(&admin, &config);
+
+    // No minimum by default: any confidence is served
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &6000);
+    assert_eq!(client.get_min_confidence(), 0);
+    assert_eq!(client.get_price(&symbol_short!("XLM")).confidence, 6000);
+
+    client.set_min_confidence(&admin, &8000);
+    let result = client.try_get_price(&symbol_short!("XLM"));
+    assert_eq!(result.err(), Some(Ok(OracleError::LowConfidence)));
+
+    // A confident update is accepted again
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &9500);
+    assert_eq!(client.get_price(&symbol_short!("XLM")).price, 10_000_000_000_000);
+
+    // Confidence is bounded by 10000
+    let result = client.try_update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &10001);
+    assert_eq!(result, Err(Ok(OracleError::InvalidParams)));
+}
+
 #[test]
 fn test_volatility_calculation() {
     let env = Env::default();
@@ -102,6 +430,8 @@ fn test_volatility_calculation() {
         decimals: 7,
         base_ltv: 7500,
         liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
@@ -118,13 +448,157 @@ fn test_volatility_calculation() {
     ];
 
     for price in prices.iter() {
-        client.update_price(&admin, &symbol_short!("XLM"), price);
+        client.update_price(&admin, &symbol_short!("XLM"), price, &10000);
     }
 
     let volatility_data = client.get_volatility(&symbol_short!("XLM"));
     assert!(volatility_data.volatility_7d > 0);
 }
 
+#[test]
+fn test_ewma_volatility_down_weights_old_spike() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let config = AssetConfig {
+        symbol: symbol_short!("XLM"),
+        contract: Address::generate(&env),
+        decimals: 7,
+        base_ltv: 7500,
+        liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
+    };
+
+    client.add_asset(&admin, &config);
+
+    // Old spike: large swings at the start of the window
+    let spike = [
+        10_000_000_000_000i128, // $0.10
+        13_000_000_000_000i128, // $0.13 (+30%)
+        9_000_000_000_000i128,  // $0.09 (-30%)
+        12_000_000_000_000i128, // $0.12 (+33%)
+        10_000_000_000_000i128, // $0.10 (-16%)
+    ];
+    for price in spike.iter() {
+        client.update_price(&admin, &symbol_short!("XLM"), price, &10000);
+    }
+
+    // Recent calm: small alternating moves (+/-1%)
+    for i in 0..25 {
+        let price = if i % 2 == 0 {
+            10_100_000_000_000i128
+        } else {
+            10_000_000_000_000i128
+        };
+        client.update_price(&admin, &symbol_short!("XLM"), &price, &10000);
+    }
+
+    let volatility_data = client.get_volatility(&symbol_short!("XLM"));
+    assert!(volatility_data.volatility_30d > 0);
+    assert!(volatility_data.volatility_ewma > 0);
+    assert!(
+        volatility_data.volatility_ewma < volatility_data.volatility_30d,
+        "EWMA should weight the recent calm period more than the old spike"
+    );
+}
+
+#[test]
+fn test_history_capacity_enables_90_day_volatility() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let config = AssetConfig {
+        symbol: symbol_short!("XLM"),
+        contract: Address::generate(&env),
+        decimals: 7,
+        base_ltv: 7500,
+        liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
+    };
+
+    client.add_asset(&admin, &config);
+
+    assert_eq!(client.get_history_capacity(), 30);
+    assert_eq!(
+        client.try_get_historical_volatility(&symbol_short!("XLM"), &90),
+        Err(Ok(OracleError::InvalidParams))
+    );
+
+    // Capacity is bounded on both sides
+    assert_eq!(
+        client.try_set_history_capacity(&admin, &29),
+        Err(Ok(OracleError::InvalidParams))
+    );
+    assert_eq!(
+        client.try_set_history_capacity(&admin, &366),
+        Err(Ok(OracleError::InvalidParams))
+    );
+    client.set_history_capacity(&admin, &90);
+
+    // 90 daily points alternating +/-2%
+    for i in 0..90 {
+        let price = if i % 2 == 0 {
+            10_000_000_000_000i128
+        } else {
+            10_200_000_000_000i128
+        };
+        client.update_price(&admin, &symbol_short!("XLM"), &price, &10000);
+    }
+
+    let volatility_data = client.get_volatility(&symbol_short!("XLM"));
+    assert_eq!(volatility_data.price_history.len(), 90);
+
+    let vol_90d = client.get_historical_volatility(&symbol_short!("XLM"), &90);
+    assert!(vol_90d > 0);
+
+    // A further update keeps the window at 90 points
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &10000);
+    assert_eq!(client.get_volatility(&symbol_short!("XLM")).price_history.len(), 90);
+}
+
+#[test]
+fn test_set_ewma_lambda_rejects_out_of_range() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    client.set_ewma_lambda(&admin, &9000);
+
+    assert_eq!(
+        client.try_set_ewma_lambda(&admin, &0),
+        Err(Ok(OracleError::InvalidParams))
+    );
+    assert_eq!(
+        client.try_set_ewma_lambda(&admin, &10000),
+        Err(Ok(OracleError::InvalidParams))
+    );
+}
+
 #[test]
 fn test_safe_borrow_calculation() {
     let env = Env::default();
@@ -144,6 +618,8 @@ fn test_safe_borrow_calculation() {
         decimals: 7,
         base_ltv: 7500,
         liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
@@ -160,7 +636,7 @@ fn test_safe_borrow_calculation() {
     ];
 
     for price in prices.iter() {
-        client.update_price(&admin, &symbol_short!("XLM"), price);
+        client.update_price(&admin, &symbol_short!("XLM"), price, &10000);
     }
 
     // Collateral value: $10,000 (14 decimals)
@@ -180,6 +656,49 @@ fn test_safe_borrow_calculation() {
     assert!(safe_borrow > 0);
 }
 
+#[test]
+fn test_safe_borrow_zero_for_extreme_volatility() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    let config = AssetConfig {
+        symbol: symbol_short!("XLM"),
+        contract: Address::generate(&env),
+        decimals: 7,
+        base_ltv: 7500,
+        liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
+    };
+
+    client.add_asset(&admin, &config);
+
+    // Price doubling and halving every update, enough points for 30-day volatility
+    for i in 0..30 {
+        let price = if i % 2 == 0 { 10_000_000_000_000i128 } else { 20_000_000_000_000i128 };
+        client.update_price(&admin, &symbol_short!("XLM"), &price, &10000);
+    }
+    assert!(client.get_volatility(&symbol_short!("XLM")).volatility_30d >= 10000);
+
+    // The adjustment exceeds the base LTV: non-borrowable, not the 30% floor
+    let safe_borrow = client.calculate_safe_borrow(
+        &symbol_short!("XLM"),
+        &100_000_000_000_000_000i128,
+        &7500,
+        &10000,
+        &365,
+    );
+    assert_eq!(safe_borrow, 0);
+}
+
 #[test]
 fn test_integer_sqrt() {
     // Test the internal sqrt function through calculate_safe_borrow
@@ -223,6 +742,8 @@ fn test_blend_price_format_14_decimals() {
         decimals: 7,
         base_ltv: 7500,
         liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
@@ -237,7 +758,7 @@ fn test_blend_price_format_14_decimals() {
     ];
 
     for (price, description) in test_prices.iter() {
-        client.update_price(&admin, &symbol_short!("XLM"), price);
+        client.update_price(&admin, &symbol_short!("XLM"), price, &10000);
         let price_data = client.get_price(&symbol_short!("XLM"));
 
         // Verify price is returned exactly as stored (14 decimals)
@@ -266,13 +787,15 @@ fn test_blend_price_staleness_check() {
         decimals: 8,
         base_ltv: 6000,
         liquidation_threshold: 7000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
 
     // Update price
     let price = 4_500_000_000_000_000i128; // $45,000 in 14 decimals
-    client.update_price(&admin, &symbol_short!("BTC"), &price);
+    client.update_price(&admin, &symbol_short!("BTC"), &price, &10000);
 
     // Price should be retrievable immediately
     let price_data = client.get_price(&symbol_short!("BTC"));
@@ -286,6 +809,111 @@ fn test_blend_price_staleness_check() {
     assert!(client.admin() == admin, "Admin should be set");
 }
 
+#[test]
+fn test_per_asset_staleness_threshold() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+    client.set_staleness_threshold(&admin, &300);
+
+    // Volatile asset follows the global 5 minute threshold
+    client.add_asset(
+        &admin,
+        &AssetConfig {
+            symbol: symbol_short!("XLM"),
+            contract: Address::generate(&env),
+            decimals: 7,
+            base_ltv: 7500,
+            liquidation_threshold: 8000,
+            staleness_threshold: 0,
+            feed_decimals: 14,
+        },
+    );
+
+    // Slow-updating stablecoin tolerates prices up to a day old
+    client.add_asset(
+        &admin,
+        &AssetConfig {
+            symbol: symbol_short!("USDC"),
+            contract: Address::generate(&env),
+            decimals: 7,
+            base_ltv: 9000,
+            liquidation_threshold: 9500,
+            staleness_threshold: 86_400,
+            feed_decimals: 14,
+        },
+    );
+
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &10000);
+    client.update_price(&admin, &symbol_short!("USDC"), &100_000_000_000_000, &10000);
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+
+    let result = client.try_get_price(&symbol_short!("XLM"));
+    assert_eq!(result.err(), Some(Ok(OracleError::StalePrice)));
+    assert_eq!(client.get_price(&symbol_short!("USDC")).price, 100_000_000_000_000);
+}
+
+#[test]
+fn test_pause_single_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(OracleAdapterContract, ());
+    let client = OracleAdapterContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+
+    client.initialize(&admin, &oracle);
+
+    for (symbol, base_ltv) in [(symbol_short!("XLM"), 7500), (symbol_short!("BTC"), 8000)] {
+        client.add_asset(
+            &admin,
+            &AssetConfig {
+                symbol,
+                contract: Address::generate(&env),
+                decimals: 7,
+                base_ltv,
+                liquidation_threshold: 8500,
+                staleness_threshold: 0,
+                feed_decimals: 14,
+            },
+        );
+    }
+
+    client.update_price(&admin, &symbol_short!("XLM"), &10_000_000_000_000, &10000);
+    client.update_price(&admin, &symbol_short!("BTC"), &6_000_000_000_000_000_000, &10000);
+
+    let result = client.try_pause_asset(&oracle, &symbol_short!("XLM"));
+    assert_eq!(result, Err(Ok(OracleError::Unauthorized)));
+
+    client.pause_asset(&admin, &symbol_short!("XLM"));
+    assert!(client.is_asset_paused(&symbol_short!("XLM")));
+
+    // Quarantined asset is rejected everywhere it is read
+    let result = client.try_get_price(&symbol_short!("XLM"));
+    assert_eq!(result.err(), Some(Ok(OracleError::AssetPaused)));
+    let result = client.try_get_volatility(&symbol_short!("XLM"));
+    assert_eq!(result.err(), Some(Ok(OracleError::AssetPaused)));
+    let result = client.try_calculate_safe_borrow(&symbol_short!("XLM"), &1000, &7500, &100, &30);
+    assert_eq!(result.err(), Some(Ok(OracleError::AssetPaused)));
+
+    // Other feeds keep working
+    assert_eq!(client.get_price(&symbol_short!("BTC")).price, 6_000_000_000_000_000_000);
+
+    client.unpause_asset(&admin, &symbol_short!("XLM"));
+    assert!(!client.is_asset_paused(&symbol_short!("XLM")));
+    assert_eq!(client.get_price(&symbol_short!("XLM")).price, 10_000_000_000_000);
+}
+
 #[test]
 fn test_blend_multiple_assets_14_decimals() {
     // Verify that multiple assets can be tracked with 14-decimal prices
@@ -316,9 +944,11 @@ fn test_blend_multiple_assets_14_decimals() {
             decimals: *decimals,
             base_ltv: 7500,
             liquidation_threshold: 8000,
+            staleness_threshold: 0,
+            feed_decimals: 14,
         };
         client.add_asset(&admin, &config);
-        client.update_price(&admin, symbol, price);
+        client.update_price(&admin, symbol, price, &10000);
     }
 
     // Verify all prices are in 14-decimal format
@@ -350,6 +980,8 @@ fn test_blend_safe_borrow_with_14_decimal_prices() {
         decimals: 7,
         base_ltv: 7500,
         liquidation_threshold: 8000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
@@ -366,7 +998,7 @@ fn test_blend_safe_borrow_with_14_decimal_prices() {
     ];
 
     for price in prices.iter() {
-        client.update_price(&admin, &symbol_short!("XLM"), price);
+        client.update_price(&admin, &symbol_short!("XLM"), price, &10000);
     }
 
     // Collateral value: $10,000 in 14 decimals
@@ -410,23 +1042,25 @@ fn test_blend_price_precision_edge_cases() {
         decimals: 18,
         base_ltv: 5000,
         liquidation_threshold: 6000,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
 
     // Test very small price (1 wei in 14 decimals)
     let small_price = 1i128;
-    client.update_price(&admin, &symbol_short!("TEST"), &small_price);
+    client.update_price(&admin, &symbol_short!("TEST"), &small_price, &10000);
     assert_eq!(client.get_price(&symbol_short!("TEST")).price, small_price);
 
     // Test very large price (max i128 / 2 to avoid overflow)
     let large_price = i128::MAX / 2;
-    client.update_price(&admin, &symbol_short!("TEST"), &large_price);
+    client.update_price(&admin, &symbol_short!("TEST"), &large_price, &10000);
     assert_eq!(client.get_price(&symbol_short!("TEST")).price, large_price);
 
     // Test typical stablecoin price ($1.00)
     let stablecoin_price = 100_000_000_000_000i128;
-    client.update_price(&admin, &symbol_short!("TEST"), &stablecoin_price);
+    client.update_price(&admin, &symbol_short!("TEST"), &stablecoin_price, &10000);
     assert_eq!(client.get_price(&symbol_short!("TEST")).price, stablecoin_price);
 }
 
@@ -451,6 +1085,8 @@ fn test_blend_volatility_with_14_decimal_prices() {
         decimals: 8,
         base_ltv: 6000,
         liquidation_threshold: 7500,
+        staleness_threshold: 0,
+        feed_decimals: 14,
     };
 
     client.add_asset(&admin, &config);
@@ -491,7 +1127,7 @@ fn test_blend_volatility_with_14_decimal_prices() {
     ];
 
     for price in prices.iter() {
-        client.update_price(&admin, &symbol_short!("VOL"), price);
+        client.update_price(&admin, &symbol_short!("VOL"), price, &10000);
     }
 
     let volatility_data = client.get_volatility(&symbol_short!("VOL"));
@@ -7,8 +7,9 @@
 //!
 //! This policy:
 //! - Limits maximum borrow amount per transaction
-//! - Tracks cumulative borrows within a time window
+//! - Tracks cumulative borrows within a fixed or rolling time window
 //! - Enforces rate limiting on borrow operations
+//! - Optionally caps cumulative borrows across all of an account's rules
 
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec,
@@ -26,8 +27,26 @@ pub enum DataKey {
     /// Usage tracking for a specific account + rule
     /// Key: (account_address, rule_id)
     Usage(Address, BytesN<32>),
+    /// Account-wide cumulative limit shared by all rules
+    /// Key: account_address
+    AccountLimit(Address),
+    /// Usage tracking against the account-wide limit
+    /// Key: account_address
+    AccountUsage(Address),
+    /// Rule ids the policy is installed under for an account
+    /// Key: account_address
+    AccountRules(Address),
+    /// Recent borrows for a rolling-window rule: Vec<(timestamp, amount)>
+    /// Key: (account_address, rule_id)
+    BorrowLog(Address, BytesN<32>),
 }
 
+/// Most borrows a rolling-window rule remembers individually
+///
+/// Beyond this the two oldest entries are merged under the newer timestamp,
+/// which keeps the older amount counted slightly longer rather than less.
+const MAX_BORROW_LOG_ENTRIES: u32 = 32;
+
 /// Policy configuration
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -40,6 +59,9 @@ pub struct BorrowLimitConfig {
     pub time_window: u64,
     /// Pool contract address this policy applies to
     pub pool_contract: Address,
+    /// Expire each borrow `time_window` after it happened instead of
+    /// resetting the whole window at once
+    pub rolling_window: bool,
 }
 
 /// Usage tracking
@@ -52,6 +74,16 @@ pub struct BorrowUsage {
     pub window_start: u64,
 }
 
+/// Account-wide limit applied across every rule of an account
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AccountLimit {
+    /// Maximum cumulative borrow across all rules within the time window
+    pub max_cumulative: i128,
+    /// Time window for the limit (in seconds)
+    pub time_window: u64,
+}
+
 /// Installation parameters for the policy
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -64,6 +96,8 @@ pub struct InstallParams {
     pub time_window: u64,
     /// Pool contract address
     pub pool_contract: Address,
+    /// Use a rolling window instead of a fixed one
+    pub rolling_window: bool,
 }
 
 #[contracterror]
@@ -82,6 +116,8 @@ pub enum PolicyError {
     NotInstalled = 5,
     /// Invalid function call (not a borrow)
     InvalidFunction = 6,
+    /// Borrow amount exceeds the account-wide cumulative limit
+    ExceedsAccountLimit = 7,
 }
 
 #[contract]
@@ -120,6 +156,7 @@ impl BorrowLimitPolicy {
             max_cumulative: params.max_cumulative,
             time_window: params.time_window,
             pool_contract: params.pool_contract,
+            rolling_window: params.rolling_window,
         };
 
         // Store config keyed by account + rule_id
@@ -136,6 +173,15 @@ impl BorrowLimitPolicy {
             .persistent()
             .set(&DataKey::Usage(account.clone(), rule_id.clone()), &usage);
 
+        // Track the rule so it can be enumerated per account
+        let mut rules = Self::get_account_rules(env.clone(), account.clone());
+        if !rules.contains(&rule_id) {
+            rules.push_back(rule_id.clone());
+            env.storage()
+                .persistent()
+                .set(&DataKey::AccountRules(account.clone()), &rules);
+        }
+
         env.events().publish(
             (symbol_short!("policy"), symbol_short!("install")),
             (&account, &rule_id),
@@ -171,25 +217,21 @@ impl BorrowLimitPolicy {
             return Ok(false);
         }
 
-        // Get current usage
-        let mut usage: BorrowUsage = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Usage(account.clone(), rule_id.clone()))
-            .unwrap_or_default();
-
-        // Check if we need to reset the window
-        let current_time = env.ledger().timestamp();
-        if current_time >= usage.window_start + config.time_window {
-            // Window expired, would reset
-            usage.cumulative_borrowed = 0;
-        }
+        // Get current usage, as it would be after any window reset
+        let (usage, _) = Self::live_usage(&env, &account, &rule_id, &config);
 
         // Check cumulative limit
         if usage.cumulative_borrowed + amount > config.max_cumulative {
             return Ok(false);
         }
 
+        // Check the account-wide limit shared by all rules
+        if let Some((limit, account_usage)) = Self::account_usage(&env, &account) {
+            if account_usage.cumulative_borrowed + amount > limit.max_cumulative {
+                return Ok(false);
+            }
+        }
+
         Ok(true)
     }
 
@@ -218,32 +260,48 @@ impl BorrowLimitPolicy {
             return Err(PolicyError::ExceedsPerTxLimit);
         }
 
-        // Get and update usage
-        let mut usage: BorrowUsage = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Usage(account.clone(), rule_id.clone()))
-            .unwrap_or_default();
-
-        let current_time = env.ledger().timestamp();
-
-        // Reset window if expired
-        if current_time >= usage.window_start + config.time_window {
-            usage.cumulative_borrowed = 0;
-            usage.window_start = current_time;
-        }
+        // Get usage with the window reset or expired borrows dropped
+        let (mut usage, mut log) = Self::live_usage(&env, &account, &rule_id, &config);
 
         // Check cumulative limit
         if usage.cumulative_borrowed + amount > config.max_cumulative {
             return Err(PolicyError::ExceedsCumulativeLimit);
         }
 
+        // Check the account-wide limit, so splitting borrows across rules
+        // cannot exceed it
+        let account_usage = Self::account_usage(&env, &account);
+        if let Some((limit, shared)) = &account_usage {
+            if shared.cumulative_borrowed + amount > limit.max_cumulative {
+                return Err(PolicyError::ExceedsAccountLimit);
+            }
+        }
+
         // Update usage
         usage.cumulative_borrowed += amount;
+        if config.rolling_window {
+            log.push_back((env.ledger().timestamp(), amount));
+            if log.len() > MAX_BORROW_LOG_ENTRIES {
+                let (_, oldest) = log.pop_front_unchecked();
+                let (timestamp, next) = log.get_unchecked(0);
+                log.set(0, (timestamp, oldest + next));
+            }
+            usage.window_start = log.get_unchecked(0).0;
+            env.storage()
+                .persistent()
+                .set(&DataKey::BorrowLog(account.clone(), rule_id.clone()), &log);
+        }
         env.storage()
             .persistent()
             .set(&DataKey::Usage(account.clone(), rule_id.clone()), &usage);
 
+        if let Some((_, mut shared)) = account_usage {
+            shared.cumulative_borrowed += amount;
+            env.storage()
+                .persistent()
+                .set(&DataKey::AccountUsage(account.clone()), &shared);
+        }
+
         env.events().publish(
             (symbol_short!("borrow"), symbol_short!("enforce")),
             (&account, amount),
@@ -270,6 +328,24 @@ impl BorrowLimitPolicy {
         env.storage()
             .persistent()
             .remove(&DataKey::Usage(account.clone(), rule_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BorrowLog(account.clone(), rule_id.clone()));
+
+        // Drop the rule from the account's list
+        let mut rules = Self::get_account_rules(env.clone(), account.clone());
+        if let Some(index) = rules.first_index_of(&rule_id) {
+            rules.remove(index);
+            if rules.is_empty() {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::AccountRules(account.clone()));
+            } else {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::AccountRules(account.clone()), &rules);
+            }
+        }
 
         env.events().publish(
             (symbol_short!("policy"), symbol_short!("uninstall")),
@@ -303,7 +379,42 @@ impl BorrowLimitPolicy {
             .get(&DataKey::Usage(account, rule_id))
     }
 
+    /// Get the rule ids the policy is installed under for an account
+    pub fn get_account_rules(env: Env, account: Address) -> Vec<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccountRules(account))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the usage of every rule installed for an account
+    pub fn get_all_usage(env: Env, account: Address) -> Vec<(BytesN<32>, BorrowUsage)> {
+        let mut all_usage = Vec::new(&env);
+        for rule_id in Self::get_account_rules(env.clone(), account.clone()).iter() {
+            let usage = Self::get_usage(env.clone(), account.clone(), rule_id.clone())
+                .unwrap_or_default();
+            all_usage.push_back((rule_id, usage));
+        }
+        all_usage
+    }
+
+    /// Get the account-wide limit, if one is set
+    pub fn get_account_limit(env: Env, account: Address) -> Option<AccountLimit> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccountLimit(account))
+    }
+
+    /// Get current usage against the account-wide limit
+    pub fn get_account_usage(env: Env, account: Address) -> Option<BorrowUsage> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccountUsage(account))
+    }
+
     /// Get remaining borrow capacity for an account/rule
+    ///
+    /// Also bounded by the account-wide limit when one is set.
     pub fn remaining_capacity(
         env: Env,
         account: Address,
@@ -315,25 +426,53 @@ impl BorrowLimitPolicy {
             .get(&DataKey::Config(account.clone(), rule_id.clone()))
             .ok_or(PolicyError::NotInstalled)?;
 
-        let mut usage: BorrowUsage = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Usage(account.clone(), rule_id.clone()))
-            .unwrap_or_default();
-
-        let current_time = env.ledger().timestamp();
+        let (usage, _) = Self::live_usage(&env, &account, &rule_id, &config);
 
-        // Reset if window expired
-        if current_time >= usage.window_start + config.time_window {
-            usage.cumulative_borrowed = 0;
+        let mut remaining = config.max_cumulative - usage.cumulative_borrowed;
+        if let Some((limit, account_usage)) = Self::account_usage(&env, &account) {
+            remaining = remaining.min(limit.max_cumulative - account_usage.cumulative_borrowed);
         }
-
-        let remaining = config.max_cumulative - usage.cumulative_borrowed;
         let capped = remaining.min(config.max_per_tx);
 
         Ok(if capped > 0 { capped } else { 0 })
     }
 
+    /// Seconds until the cumulative limit of an account/rule refreshes
+    ///
+    /// For a fixed window this is when the window resets; for a rolling
+    /// window, when its oldest counted borrow expires. Returns 0 when the
+    /// window has already expired, or a rolling window counts no borrows.
+    pub fn time_until_reset(
+        env: Env,
+        account: Address,
+        rule_id: BytesN<32>,
+    ) -> Result<u64, PolicyError> {
+        let config: BorrowLimitConfig = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Config(account.clone(), rule_id.clone()))
+            .ok_or(PolicyError::NotInstalled)?;
+
+        let window_start = if config.rolling_window {
+            let (usage, _) = Self::live_usage(&env, &account, &rule_id, &config);
+            if usage.cumulative_borrowed == 0 {
+                return Ok(0);
+            }
+            usage.window_start
+        } else {
+            // An expired fixed window has not been restarted yet, so it resets now
+            let usage: BorrowUsage = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Usage(account, rule_id))
+                .unwrap_or_default();
+            usage.window_start
+        };
+
+        let reset_at = window_start + config.time_window;
+        Ok(reset_at.saturating_sub(env.ledger().timestamp()))
+    }
+
     // ============ Admin Functions ============
 
     /// Update the configuration for an account/rule (admin only)
@@ -356,6 +495,7 @@ impl BorrowLimitPolicy {
             max_cumulative: params.max_cumulative,
             time_window: params.time_window,
             pool_contract: params.pool_contract,
+            rolling_window: params.rolling_window,
         };
 
         env.storage()
@@ -365,6 +505,51 @@ impl BorrowLimitPolicy {
         Ok(())
     }
 
+    /// Set or clear the account-wide cumulative limit (admin only)
+    ///
+    /// The limit applies to the sum of borrows enforced under every rule of
+    /// the account, on top of each rule's own window. Setting a limit
+    /// restarts its usage window.
+    pub fn set_account_limit(
+        env: Env,
+        caller: Address,
+        account: Address,
+        limit: Option<AccountLimit>,
+    ) -> Result<(), PolicyError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        match limit {
+            Some(limit) => {
+                if limit.max_cumulative <= 0 || limit.time_window == 0 {
+                    return Err(PolicyError::InvalidParams);
+                }
+
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::AccountLimit(account.clone()), &limit);
+
+                let usage = BorrowUsage {
+                    cumulative_borrowed: 0,
+                    window_start: env.ledger().timestamp(),
+                };
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::AccountUsage(account), &usage);
+            }
+            None => {
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::AccountLimit(account.clone()));
+                env.storage()
+                    .persistent()
+                    .remove(&DataKey::AccountUsage(account));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get admin address
     pub fn admin(env: Env) -> Result<Address, PolicyError> {
         env.storage()
@@ -383,6 +568,77 @@ impl BorrowLimitPolicy {
         Ok(())
     }
 
+    /// Usage of a rule as of now, plus its live borrow log
+    ///
+    /// A fixed window resets once `time_window` has passed since it started.
+    /// A rolling window counts only logged borrows younger than
+    /// `time_window`, and its start is the oldest of them. The log is empty
+    /// for fixed windows.
+    fn live_usage(
+        env: &Env,
+        account: &Address,
+        rule_id: &BytesN<32>,
+        config: &BorrowLimitConfig,
+    ) -> (BorrowUsage, Vec<(u64, i128)>) {
+        let current_time = env.ledger().timestamp();
+
+        let mut usage: BorrowUsage = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Usage(account.clone(), rule_id.clone()))
+            .unwrap_or_default();
+        let mut log = Vec::new(env);
+
+        if config.rolling_window {
+            let entries: Vec<(u64, i128)> = env
+                .storage()
+                .persistent()
+                .get(&DataKey::BorrowLog(account.clone(), rule_id.clone()))
+                .unwrap_or(Vec::new(env));
+
+            usage.cumulative_borrowed = 0;
+            for (timestamp, amount) in entries.iter() {
+                if current_time < timestamp + config.time_window {
+                    usage.cumulative_borrowed += amount;
+                    log.push_back((timestamp, amount));
+                }
+            }
+            usage.window_start = match log.first() {
+                Some((timestamp, _)) => timestamp,
+                None => current_time,
+            };
+        } else if current_time >= usage.window_start + config.time_window {
+            usage.cumulative_borrowed = 0;
+            usage.window_start = current_time;
+        }
+
+        (usage, log)
+    }
+
+    /// Account-wide limit and its usage, with an expired window reset
+    ///
+    /// Returns `None` when the account has no account-wide limit.
+    fn account_usage(env: &Env, account: &Address) -> Option<(AccountLimit, BorrowUsage)> {
+        let limit: AccountLimit = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccountLimit(account.clone()))?;
+
+        let mut usage: BorrowUsage = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AccountUsage(account.clone()))
+            .unwrap_or_default();
+
+        let current_time = env.ledger().timestamp();
+        if current_time >= usage.window_start + limit.time_window {
+            usage.cumulative_borrowed = 0;
+            usage.window_start = current_time;
+        }
+
+        Some((limit, usage))
+    }
+
     /// Extract borrow amount from function arguments
     ///
     /// Assumes the borrow function signature is: borrow(user: Address, amount: i128)
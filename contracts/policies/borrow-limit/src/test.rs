@@ -1,7 +1,10 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, vec, Env, IntoVal};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Env, IntoVal,
+};
 
 fn create_rule_id(env: &Env) -> BytesN<32> {
     BytesN::from_array(env, &[1u8; 32])
@@ -41,6 +44,7 @@ fn test_install_and_get_config() {
         max_cumulative: 5000_0000000,  // 5000 USDC per window
         time_window: 86400,            // 24 hours
         pool_contract: pool.clone(),
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -74,6 +78,7 @@ fn test_can_enforce_within_limits() {
         max_cumulative: 5000_0000000,
         time_window: 86400,
         pool_contract: pool.clone(),
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -117,6 +122,7 @@ fn test_can_enforce_exceeds_per_tx() {
         max_cumulative: 5000_0000000,
         time_window: 86400,
         pool_contract: pool.clone(),
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -160,6 +166,7 @@ fn test_enforce_updates_usage() {
         max_cumulative: 5000_0000000,
         time_window: 86400,
         pool_contract: pool.clone(),
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -196,6 +203,216 @@ fn test_enforce_updates_usage() {
     assert_eq!(usage.cumulative_borrowed, 1000_0000000);
 }
 
+#[test]
+fn test_account_limit_spans_rules() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BorrowLimitPolicy, ());
+    let client = BorrowLimitPolicyClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let rule_a = create_rule_id(&env);
+    let rule_b = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.initialize(&admin);
+
+    let params = InstallParams {
+        max_per_tx: 1000_0000000,
+        max_cumulative: 1000_0000000,
+        time_window: 86400,
+        pool_contract: pool.clone(),
+        rolling_window: false,
+    };
+    client.install(&account, &rule_a, &params);
+    client.install(&account, &rule_b, &params);
+
+    // Each rule allows 1000 USDC, but the account as a whole only 1500
+    client.set_account_limit(
+        &admin,
+        &account,
+        &Some(AccountLimit {
+            max_cumulative: 1500_0000000,
+            time_window: 86400,
+        }),
+    );
+
+    let borrow_amount: i128 = 800_0000000;
+    let args = vec![
+        &env,
+        account.clone().into_val(&env),
+        borrow_amount.into_val(&env),
+    ];
+    let function = soroban_sdk::symbol_short!("borrow");
+
+    client.enforce(&account, &rule_a, &pool, &function, &args);
+    assert_eq!(client.get_account_usage(&account).unwrap().cumulative_borrowed, 800_0000000);
+    assert_eq!(client.remaining_capacity(&account, &rule_b), 700_0000000);
+
+    // Rule B is untouched, yet the account-wide cap blocks the second borrow
+    assert!(!client.can_enforce(&account, &rule_b, &pool, &function, &args));
+    let result = client.try_enforce(&account, &rule_b, &pool, &function, &args);
+    assert_eq!(result, Err(Ok(PolicyError::ExceedsAccountLimit)));
+    assert_eq!(client.get_usage(&account, &rule_b).unwrap().cumulative_borrowed, 0);
+
+    // Without the account-wide limit only the per-rule windows apply
+    client.set_account_limit(&admin, &account, &None);
+    client.enforce(&account, &rule_b, &pool, &function, &args);
+}
+
+#[test]
+fn test_get_all_usage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BorrowLimitPolicy, ());
+    let client = BorrowLimitPolicyClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let rule_a = create_rule_id(&env);
+    let rule_b = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.initialize(&admin);
+    assert!(client.get_all_usage(&account).is_empty());
+
+    let params = InstallParams {
+        max_per_tx: 1000_0000000,
+        max_cumulative: 5000_0000000,
+        time_window: 86400,
+        pool_contract: pool.clone(),
+        rolling_window: false,
+    };
+    client.install(&account, &rule_a, &params);
+    client.install(&account, &rule_b, &params);
+    // Reinstalling does not list the rule twice
+    client.install(&account, &rule_a, &params);
+    assert_eq!(client.get_account_rules(&account), vec![&env, rule_a.clone(), rule_b.clone()]);
+
+    let borrow_amount: i128 = 300_0000000;
+    let args = vec![
+        &env,
+        account.clone().into_val(&env),
+        borrow_amount.into_val(&env),
+    ];
+    client.enforce(&account, &rule_b, &pool, &soroban_sdk::symbol_short!("borrow"), &args);
+
+    let all_usage = client.get_all_usage(&account);
+    assert_eq!(all_usage.len(), 2);
+    let (rule, usage) = all_usage.get(0).unwrap();
+    assert_eq!(rule, rule_a);
+    assert_eq!(usage.cumulative_borrowed, 0);
+    let (rule, usage) = all_usage.get(1).unwrap();
+    assert_eq!(rule, rule_b);
+    assert_eq!(usage.cumulative_borrowed, 300_0000000);
+
+    client.uninstall(&account, &rule_a);
+    assert_eq!(client.get_account_rules(&account), vec![&env, rule_b]);
+}
+
+#[test]
+fn test_rolling_window_blocks_boundary_double_borrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BorrowLimitPolicy, ());
+    let client = BorrowLimitPolicyClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let fixed_rule = create_rule_id(&env);
+    let rolling_rule = BytesN::from_array(&env, &[2u8; 32]);
+
+    client.initialize(&admin);
+
+    let mut params = InstallParams {
+        max_per_tx: 1000_0000000,
+        max_cumulative: 1000_0000000,
+        time_window: 86400,
+        pool_contract: pool.clone(),
+        rolling_window: false,
+    };
+    client.install(&account, &fixed_rule, &params);
+    params.rolling_window = true;
+    client.install(&account, &rolling_rule, &params);
+
+    let borrow_amount: i128 = 1000_0000000;
+    let args = vec![
+        &env,
+        account.clone().into_val(&env),
+        borrow_amount.into_val(&env),
+    ];
+    let function = soroban_sdk::symbol_short!("borrow");
+
+    // Max out both rules one second before the first window ends
+    env.ledger().with_mut(|li| li.timestamp = 86399);
+    client.enforce(&account, &fixed_rule, &pool, &function, &args);
+    client.enforce(&account, &rolling_rule, &pool, &function, &args);
+
+    // One second later the fixed window resets and allows a second full borrow
+    env.ledger().with_mut(|li| li.timestamp = 86400);
+    client.enforce(&account, &fixed_rule, &pool, &function, &args);
+
+    // The rolling window still counts the borrow made a second ago
+    assert_eq!(client.remaining_capacity(&account, &rolling_rule), 0);
+    assert!(!client.can_enforce(&account, &rolling_rule, &pool, &function, &args));
+    let result = client.try_enforce(&account, &rolling_rule, &pool, &function, &args);
+    assert_eq!(result, Err(Ok(PolicyError::ExceedsCumulativeLimit)));
+
+    // Until that borrow itself is a full window old
+    env.ledger().with_mut(|li| li.timestamp = 86399 + 86400);
+    client.enforce(&account, &rolling_rule, &pool, &function, &args);
+    let usage = client.get_usage(&account, &rolling_rule).unwrap();
+    assert_eq!(usage.cumulative_borrowed, 1000_0000000);
+    assert_eq!(usage.window_start, 86399 + 86400);
+}
+
+#[test]
+fn test_time_until_reset() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(BorrowLimitPolicy, ());
+    let client = BorrowLimitPolicyClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let account = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let rule_id = create_rule_id(&env);
+
+    client.initialize(&admin);
+
+    let result = client.try_time_until_reset(&account, &rule_id);
+    assert_eq!(result, Err(Ok(PolicyError::NotInstalled)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+    let params = InstallParams {
+        max_per_tx: 1000_0000000,
+        max_cumulative: 5000_0000000,
+        time_window: 86400,
+        pool_contract: pool.clone(),
+        rolling_window: false,
+    };
+    client.install(&account, &rule_id, &params);
+    assert_eq!(client.time_until_reset(&account, &rule_id), 86400);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 3600);
+    assert_eq!(client.time_until_reset(&account, &rule_id), 86400 - 3600);
+
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 86399);
+    assert_eq!(client.time_until_reset(&account, &rule_id), 1);
+
+    // At the boundary the reset is available now
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 86400);
+    assert_eq!(client.time_until_reset(&account, &rule_id), 0);
+    env.ledger().with_mut(|li| li.timestamp = 1000 + 90000);
+    assert_eq!(client.time_until_reset(&account, &rule_id), 0);
+}
+
 #[test]
 fn test_remaining_capacity() {
     let env = Env::default();
@@ -216,6 +433,7 @@ fn test_remaining_capacity() {
         max_cumulative: 5000_0000000,
         time_window: 86400,
         pool_contract: pool.clone(),
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -265,6 +483,7 @@ fn test_uninstall() {
         max_cumulative: 5000_0000000,
         time_window: 86400,
         pool_contract: pool,
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -296,6 +515,7 @@ fn test_install_invalid_params() {
         max_cumulative: 5000_0000000,
         time_window: 86400,
         pool_contract: pool,
+        rolling_window: false,
     };
 
     client.install(&account, &rule_id, &params);
@@ -0,0 +1,14 @@
+//! Blend adapter interface used to route requests to Blend
+
+use soroban_sdk::{contractclient, Address, Env, Vec};
+use vantis_types::{HealthFactorResult, Request};
+
+/// Subset of the Blend adapter interface used by the risk engine
+///
+/// Only the generated `BlendAdapterClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "BlendAdapterClient")]
+pub trait BlendAdapterInterface {
+    fn submit(env: Env, user: Address, requests: Vec<Request>);
+    fn get_health_factor(env: Env, user: Address) -> HealthFactorResult;
+}
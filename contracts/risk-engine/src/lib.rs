@@ -10,16 +10,30 @@
 //! - Integration with Blend adapter for position queries
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
+    Symbol, Vec,
 };
 
 mod volatility;
 mod stop_loss;
 mod liquidation;
+mod pool;
+mod adapter;
+mod oracle;
+mod router;
 
 pub use volatility::VolatilityAdjustedLTV;
 pub use stop_loss::StopLossConfig;
-pub use liquidation::LiquidationResult;
+pub use liquidation::{AuctionBounds, AuctionLot, DutchAuctionParams, LiquidationResult};
+
+use adapter::BlendAdapterClient;
+use oracle::OracleClient;
+use pool::PoolClient;
+use router::RouterClient;
+use vantis_types::{
+    addresses_distinct, is_valid_hf_scale, rescale_hf, scale_decimals, DEFAULT_HF_SCALE,
+    PRICE_DECIMALS,
+};
 
 /// Storage keys
 #[contracttype]
@@ -44,6 +58,40 @@ pub enum DataKey {
     Liquidators,
     /// Protocol treasury for fees
     Treasury,
+    /// Global Dutch auction bounds
+    AuctionBounds,
+    /// Active liquidation auction per user
+    Auction(Address),
+    /// Remaining bid and lot of the active auction per user
+    AuctionLot(Address),
+    /// Market stress override for the liquidation threshold
+    StressMode,
+    /// Annual yield earned on collateral (basis points)
+    CollateralYieldRate,
+    /// Recent liquidations per user (bounded)
+    LiquidationHistory(Address),
+    /// Recent liquidations across all users (bounded)
+    GlobalLiquidationHistory,
+    /// Maximum entries kept in each liquidation history
+    LiquidationHistoryCap,
+    /// Keeper reward rate (basis points)
+    KeeperRewardBp,
+    /// Whether an address is a registered keeper
+    Keeper(Address),
+    /// Unclaimed USDC rewards per keeper
+    KeeperRewards(Address),
+    /// Fixed-point scale of health factors (scale = 1.0)
+    HfScale,
+    /// Maximum share of an auction a single fill may repay (basis points)
+    MaxFillShareBp,
+    /// Whether liquidations and stop-losses are halted
+    LiquidationsPaused,
+    /// Seconds liquidations stay frozen after an unpause
+    LiquidationGracePeriod,
+    /// Timestamp at which liquidations resume after the last unpause
+    LiquidationsResumeAt,
+    /// Tokens a swap quote may output besides USDC
+    SwapAllowlist,
 }
 
 /// Global risk parameters
@@ -73,6 +121,18 @@ pub struct RiskParameters {
     /// Minimum collateral factor (basis points)
     /// Floor for volatility-adjusted LTV
     pub min_collateral_factor: u32,
+    /// Maximum slippage a user may configure for stop-loss swaps (basis points)
+    /// e.g., 1000 = 10%
+    pub max_allowed_slippage: u32,
+    /// Smallest debt repayment accepted by `liquidate` unless it closes the position
+    /// e.g., 10_0000000 = 10 USDC
+    pub min_liquidation_debt: i128,
+    /// Seconds after an auction starts before it can be filled
+    /// e.g., 300 = 5 minutes of price discovery
+    pub min_auction_delay: u64,
+    /// Smallest bonus a liquidator receives, topped up from the protocol fee
+    /// and then the treasury; e.g., 1_0000000 = 1 USDC (0 = no floor)
+    pub min_liquidator_reward: i128,
 }
 
 impl Default for RiskParameters {
@@ -86,10 +146,36 @@ impl Default for RiskParameters {
             liquidation_penalty: 500,       // 5%
             protocol_fee: 100,              // 1%
             min_collateral_factor: 3000,    // 30% minimum
+            max_allowed_slippage: 1000,     // 10%
+            min_liquidation_debt: 10_0000000, // 10 USDC
+            min_auction_delay: 300,         // 5 minutes
+            min_liquidator_reward: 0,
         }
     }
 }
 
+impl RiskParameters {
+    /// Check the bounds enforced by `initialize` and `update_params`
+    pub fn is_valid(&self) -> bool {
+        self.max_allowed_slippage <= 10000
+            && self.min_liquidation_debt >= 0
+            && self.min_liquidator_reward >= 0
+    }
+}
+
+/// Market stress override
+///
+/// While enabled, `threshold_delta_bp` is added to the liquidation
+/// threshold so positions become liquidatable earlier.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StressMode {
+    /// Is stress mode active
+    pub enabled: bool,
+    /// Amount added to the liquidation threshold (basis points)
+    pub threshold_delta_bp: u32,
+}
+
 /// User's stop-loss configuration
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -102,6 +188,8 @@ pub struct UserStopLossConfig {
     pub swap_priority: Vec<Address>,
     /// Maximum slippage for swaps (basis points)
     pub max_slippage: u32,
+    /// Let any keeper trigger the stop-loss (false = only the user may)
+    pub allow_permissionless: bool,
 }
 
 /// Liquidation event data
@@ -126,6 +214,12 @@ pub struct LiquidationEvent {
     pub timestamp: u64,
 }
 
+/// Default number of liquidations kept per history
+const DEFAULT_LIQUIDATION_HISTORY_CAP: u32 = 10;
+
+/// Upper bound on the stop-loss keeper reward (basis points, 5%)
+const MAX_KEEPER_REWARD_BP: u32 = 500;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -150,6 +244,20 @@ pub enum RiskError {
     InsufficientCollateral = 9,
     /// Blend adapter error
     BlendAdapterError = 10,
+    /// No active liquidation auction for the user
+    AuctionNotFound = 11,
+    /// Position is still below the liquidation threshold
+    PositionStillLiquidatable = 12,
+    /// Keeper rewards cannot be paid from the treasury
+    KeeperRewardUnavailable = 13,
+    /// Auction is still inside its minimum delay
+    AuctionNotReady = 14,
+    /// Liquidations and stop-losses are paused
+    Paused = 15,
+    /// Swap output token is not on the allowlist
+    DisallowedSwapAsset = 16,
+    /// A liquidation auction is already running for the user
+    AuctionActive = 17,
 }
 
 #[contract]
@@ -166,6 +274,10 @@ impl RiskEngineContract {
     /// * `usdc_token` - USDC token address
     /// * `blend_adapter` - Blend adapter contract address for position queries
     /// * `params` - Risk parameters
+    ///
+    /// # Errors
+    /// - `InvalidParams`: Any two of the addresses are equal, or `params`
+    ///   fails the same checks as `update_params`
     pub fn initialize(
         env: Env,
         admin: Address,
@@ -174,11 +286,23 @@ impl RiskEngineContract {
         usdc_token: Address,
         blend_adapter: Address,
         params: RiskParameters,
-    ) {
+    ) -> Result<(), RiskError> {
         if env.storage().instance().has(&DataKey::Admin) {
             panic!("Already initialized");
         }
 
+        let addresses = vec![
+            &env,
+            admin.clone(),
+            oracle.clone(),
+            pool.clone(),
+            usdc_token.clone(),
+            blend_adapter.clone(),
+        ];
+        if !addresses_distinct(&addresses) || !params.is_valid() {
+            return Err(RiskError::InvalidParams);
+        }
+
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Oracle, &oracle);
         env.storage().instance().set(&DataKey::Pool, &pool);
@@ -186,6 +310,8 @@ impl RiskEngineContract {
         env.storage().instance().set(&DataKey::UsdcToken, &usdc_token);
         env.storage().instance().set(&DataKey::RiskParams, &params);
         env.storage().instance().set(&DataKey::Liquidators, &Vec::<Address>::new(&env));
+
+        Ok(())
     }
 
     /// Update risk parameters
@@ -197,6 +323,10 @@ impl RiskEngineContract {
         caller.require_auth();
         Self::require_admin(&env, &caller)?;
 
+        if !params.is_valid() {
+            return Err(RiskError::InvalidParams);
+        }
+
         env.storage().instance().set(&DataKey::RiskParams, &params);
 
         env.events().publish(
@@ -220,6 +350,149 @@ impl RiskEngineContract {
         Ok(())
     }
 
+    /// Preview the USDC received for swapping `amount` of `from_asset`
+    ///
+    /// Read-only quote from the configured swap router, so UIs can show the
+    /// expected proceeds of a stop-loss or repay-with-collateral.
+    ///
+    /// # Errors
+    /// - `InvalidParams`: `amount` is not positive
+    /// - `SwapFailed`: No router is set or the router cannot quote the pair
+    pub fn get_swap_quote(env: Env, from_asset: Address, amount: i128) -> Result<i128, RiskError> {
+        let usdc: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::UsdcToken)
+            .ok_or(RiskError::SwapFailed)?;
+
+        Self::get_swap_quote_to(env, from_asset, usdc, amount)
+    }
+
+    /// Preview the amount of `to_asset` received for swapping `amount` of
+    /// `from_asset`
+    ///
+    /// The output token must be USDC or allowlisted. Only this quote and
+    /// `get_swap_quote` enforce the allowlist; no swap is executed yet, so
+    /// there is no other router path to guard.
+    ///
+    /// # Errors
+    /// - `InvalidParams`: `amount` is not positive
+    /// - `DisallowedSwapAsset`: `to_asset` is neither USDC nor allowlisted
+    /// - `SwapFailed`: No router is set or the router cannot quote the pair
+    pub fn get_swap_quote_to(
+        env: Env,
+        from_asset: Address,
+        to_asset: Address,
+        amount: i128,
+    ) -> Result<i128, RiskError> {
+        if amount <= 0 {
+            return Err(RiskError::InvalidParams);
+        }
+
+        if !Self::is_swap_asset_allowed(env.clone(), to_asset.clone()) {
+            return Err(RiskError::DisallowedSwapAsset);
+        }
+
+        if from_asset == to_asset {
+            return Ok(amount);
+        }
+
+        let router: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::SwapRouter)
+            .ok_or(RiskError::SwapFailed)?;
+
+        let path = vec![&env, from_asset, to_asset];
+        let client = RouterClient::new(&env, &router);
+        let amounts = match client.try_router_get_amounts_out(&amount, &path) {
+            Ok(Ok(amounts)) => amounts,
+            _ => return Err(RiskError::SwapFailed),
+        };
+
+        amounts.last().ok_or(RiskError::SwapFailed)
+    }
+
+    /// Allow swaps to output `asset` (admin only)
+    ///
+    /// Intended for registered collateral; USDC is always allowed. Checked
+    /// by `get_swap_quote_to` only.
+    pub fn allow_swap_asset(env: Env, caller: Address, asset: Address) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        let mut allowlist = Self::get_swap_allowlist(env.clone());
+        if !allowlist.contains(&asset) {
+            allowlist.push_back(asset.clone());
+            env.storage().instance().set(&DataKey::SwapAllowlist, &allowlist);
+        }
+
+        env.events().publish(
+            (symbol_short!("swap"), symbol_short!("allowed")),
+            asset,
+        );
+
+        Ok(())
+    }
+
+    /// Remove `asset` from the swap output allowlist (admin only)
+    pub fn disallow_swap_asset(
+        env: Env,
+        caller: Address,
+        asset: Address,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        let mut allowlist = Self::get_swap_allowlist(env.clone());
+        if let Some(index) = allowlist.first_index_of(&asset) {
+            allowlist.remove(index);
+            env.storage().instance().set(&DataKey::SwapAllowlist, &allowlist);
+        }
+
+        env.events().publish(
+            (symbol_short!("swap"), symbol_short!("disallow")),
+            asset,
+        );
+
+        Ok(())
+    }
+
+    /// Get the allowlisted swap output tokens (USDC is implicit)
+    pub fn get_swap_allowlist(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::SwapAllowlist)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Check whether a swap may output `asset`
+    pub fn is_swap_asset_allowed(env: Env, asset: Address) -> bool {
+        let usdc: Option<Address> = env.storage().instance().get(&DataKey::UsdcToken);
+        usdc == Some(asset.clone()) || Self::get_swap_allowlist(env).contains(&asset)
+    }
+
+    /// Set the annual yield earned on collateral (admin only)
+    ///
+    /// Used by `get_effective_rate` to offset borrow cost.
+    pub fn set_collateral_yield_rate(
+        env: Env,
+        caller: Address,
+        yield_rate: u32,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if yield_rate > 10000 {
+            return Err(RiskError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::CollateralYieldRate, &yield_rate);
+        Ok(())
+    }
+
     /// Set treasury address
     pub fn set_treasury(
         env: Env,
@@ -302,7 +575,88 @@ impl RiskEngineContract {
         Ok(safe_borrow)
     }
 
+    /// Calculate safe borrow amount denominated in borrow token units
+    ///
+    /// Same as `calculate_safe_borrow`, but rescales the 14-decimal USD
+    /// result to the borrow token's precision so it can be passed straight
+    /// to the pool's `borrow`.
+    ///
+    /// # Arguments
+    /// * `asset` - Collateral asset symbol
+    /// * `collateral_value` - Collateral value in USD (14 decimals)
+    /// * `base_ltv` - Base LTV in basis points
+    /// * `borrow_token_decimals` - Decimals of the borrow token (e.g. 6 for USDC)
+    ///
+    /// # Returns
+    /// Safe borrow amount in borrow token units
+    pub fn safe_borrow_in_token(
+        env: Env,
+        asset: Symbol,
+        collateral_value: i128,
+        base_ltv: u32,
+        borrow_token_decimals: u32,
+    ) -> Result<i128, RiskError> {
+        let safe_borrow = Self::calculate_safe_borrow(env, asset, collateral_value, base_ltv)?;
+
+        Ok(scale_decimals(safe_borrow, PRICE_DECIMALS, borrow_token_decimals))
+    }
+
+    /// Get the volatility-adjusted LTV of every oracle-supported asset
+    ///
+    /// Uses each asset's base LTV from the oracle's `AssetConfig` and its
+    /// stored 30-day volatility, with the current risk parameters.
+    pub fn get_ltv_table(env: Env) -> Result<Vec<VolatilityAdjustedLTV>, RiskError> {
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        let oracle: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Oracle)
+            .ok_or(RiskError::OracleError)?;
+        let client = OracleClient::new(&env, &oracle);
+
+        let assets = match client.try_get_assets() {
+            Ok(Ok(assets)) => assets,
+            _ => return Err(RiskError::OracleError),
+        };
+
+        let mut table = Vec::new(&env);
+        for asset in assets.iter() {
+            let config = match client.try_get_asset_config(&asset) {
+                Ok(Ok(config)) => config,
+                _ => return Err(RiskError::OracleError),
+            };
+            let volatility_bp = match client.try_get_volatility(&asset) {
+                Ok(Ok(data)) => data.volatility_30d,
+                _ => return Err(RiskError::OracleError),
+            };
+
+            table.push_back(VolatilityAdjustedLTV {
+                asset,
+                base_ltv: config.base_ltv,
+                volatility: volatility_bp,
+                adjusted_ltv: volatility::calculate_adjusted_ltv(
+                    config.base_ltv,
+                    volatility_bp,
+                    params.k_factor,
+                    params.time_horizon_days,
+                    params.min_collateral_factor,
+                ),
+                k_factor: params.k_factor,
+                time_horizon: params.time_horizon_days,
+            });
+        }
+
+        Ok(table)
+    }
+
     /// Get the adjusted LTV for an asset
+    ///
+    /// Returns 0 (non-borrowable) when the adjustment exceeds the base LTV.
     fn calculate_adjusted_ltv(
         env: &Env,
         _oracle: &Address,
@@ -324,10 +678,12 @@ impl RiskEngineContract {
         let adjustment = (k_factor as i128 * volatility_bp as i128 * sqrt_t) / (1000 * 10000);
 
         // Adjusted LTV = base_ltv - adjustment
-        let adjusted_ltv = (base_ltv as i128).saturating_sub(adjustment);
+        let adjusted_ltv = base_ltv as i128 - adjustment;
 
-        // Apply minimum floor
-        let final_ltv = if adjusted_ltv < min_ltv as i128 {
+        // Non-borrowable when volatility wipes out the base LTV, else apply the floor
+        let final_ltv = if adjusted_ltv < 0 {
+            0
+        } else if adjusted_ltv < min_ltv as i128 {
             min_ltv
         } else {
             adjusted_ltv as u32
@@ -341,6 +697,70 @@ impl RiskEngineContract {
         Ok(final_ltv)
     }
 
+    // ============ Effective Rate ============
+
+    /// Get a user's net borrow rate after collateral yield
+    ///
+    /// Combines the pool's borrow rate on the user's principal with the
+    /// admin-set collateral yield on their collateral value.
+    ///
+    /// # Returns
+    /// Effective rate in basis points (negative if the user earns)
+    pub fn get_effective_rate(env: Env, user: Address) -> Result<i32, RiskError> {
+        let pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Pool)
+            .ok_or(RiskError::PoolError)?;
+        let pool_client = PoolClient::new(&env, &pool);
+
+        let borrow = match pool_client.try_get_borrow(&user) {
+            Ok(Ok(data)) => data,
+            _ => return Err(RiskError::PoolError),
+        };
+
+        let borrow_rate = match pool_client.try_get_interest_rate() {
+            Ok(Ok(rate)) => rate,
+            _ => return Err(RiskError::PoolError),
+        };
+
+        let breakdown = match pool_client.try_get_collateral_breakdown(&user) {
+            Ok(Ok(breakdown)) => breakdown,
+            _ => return Err(RiskError::PoolError),
+        };
+        let collateral_value: i128 = breakdown.iter().map(|(_, _, value, _)| value).sum();
+
+        let yield_rate: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CollateralYieldRate)
+            .unwrap_or(0);
+
+        Ok(volatility::calculate_effective_rate(
+            borrow_rate as i32,
+            yield_rate as i32,
+            borrow.principal,
+            collateral_value,
+        ))
+    }
+
+    /// Get how much more a user can borrow, as reported by the pool
+    ///
+    /// The pool is the single source of truth for borrow capacity; this view
+    /// only forwards its `get_borrow_capacity` (borrow-token units).
+    pub fn get_borrow_capacity(env: Env, user: Address) -> Result<i128, RiskError> {
+        let pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Pool)
+            .ok_or(RiskError::PoolError)?;
+
+        match PoolClient::new(&env, &pool).try_get_borrow_capacity(&user) {
+            Ok(Ok(capacity)) => Ok(capacity),
+            _ => Err(RiskError::PoolError),
+        }
+    }
+
     // ============ Stop-Loss Functions ============
 
     /// Enable stop-loss for a user
@@ -351,8 +771,21 @@ impl RiskEngineContract {
     ) -> Result<(), RiskError> {
         user.require_auth();
 
-        if config.max_slippage > 1000 {
-            // Max 10% slippage
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        if config.max_slippage > params.max_allowed_slippage {
+            return Err(RiskError::InvalidParams);
+        }
+
+        // A custom trigger must fire before liquidation and below the target
+        if config.custom_threshold != 0
+            && (config.custom_threshold <= params.liquidation_threshold
+                || config.custom_threshold >= params.target_health_factor)
+        {
             return Err(RiskError::InvalidParams);
         }
 
@@ -387,7 +820,13 @@ impl RiskEngineContract {
     /// Execute stop-loss for a user (callable by anyone when conditions met)
     ///
     /// Swaps volatile collateral to USDC to reduce debt exposure
-    /// without incurring the liquidation penalty
+    /// without incurring the liquidation penalty. The stop-loss fires once:
+    /// it is disabled afterwards and must be re-enabled by the user. Users who
+    /// opted out of `allow_permissionless` can only trigger their own
+    /// stop-loss; anyone else gets `Unauthorized`.
+    ///
+    /// # Returns
+    /// Amount of collateral to swap
     pub fn trigger_stop_loss(
         env: Env,
         caller: Address,
@@ -395,8 +834,13 @@ impl RiskEngineContract {
     ) -> Result<i128, RiskError> {
         caller.require_auth();
 
+        // Stop-losses protect the user, so they resume without the grace period
+        if Self::is_liquidations_paused(env.clone()) {
+            return Err(RiskError::Paused);
+        }
+
         // Check stop-loss is enabled
-        let config: UserStopLossConfig = env
+        let mut config: UserStopLossConfig = env
             .storage()
             .persistent()
             .get(&DataKey::StopLoss(user.clone()))
@@ -406,6 +850,10 @@ impl RiskEngineContract {
             return Err(RiskError::StopLossNotEnabled);
         }
 
+        if !config.allow_permissionless && caller != user {
+            return Err(RiskError::Unauthorized);
+        }
+
         let params: RiskParameters = env
             .storage()
             .instance()
@@ -422,11 +870,11 @@ impl RiskEngineContract {
             params.stop_loss_threshold
         };
 
-        if health_factor > threshold {
+        if health_factor > Self::to_hf_scale(&env, threshold) {
             return Err(RiskError::PositionHealthy);
         }
 
-        if health_factor < params.liquidation_threshold {
+        if health_factor < Self::liquidation_threshold_scaled(&env, &params) {
             // Already liquidatable, stop-loss too late
             return Err(RiskError::NotLiquidatable);
         }
@@ -435,15 +883,151 @@ impl RiskEngineContract {
         let swap_amount = Self::calculate_stop_loss_amount(&env, &user, &params)?;
 
         // Execute swap (would call DEX in production)
-        // For now, emit event and return the calculated amount
+        // For now, emit event and return the calculated amount. No keeper
+        // reward is credited: there are no swap proceeds to pay it from until
+        // the swap actually executes
+
+        // One-shot: a keeper cannot re-trigger the same position
+        config.enabled = false;
+        env.storage()
+            .persistent()
+            .set(&DataKey::StopLoss(user.clone()), &config);
+
         env.events().publish(
             (symbol_short!("stoploss"), symbol_short!("trigger")),
-            (&user, swap_amount),
+            (&user, swap_amount, &caller),
         );
 
         Ok(swap_amount)
     }
 
+    /// Register the caller as a keeper eligible for rewards
+    pub fn register_keeper(env: Env, keeper: Address) -> Result<(), RiskError> {
+        keeper.require_auth();
+
+        if Self::is_keeper(env.clone(), keeper.clone()) {
+            return Ok(());
+        }
+
+        // Per-keeper entries keep registration O(1) and out of instance storage
+        env.storage()
+            .persistent()
+            .set(&DataKey::Keeper(keeper.clone()), &true);
+
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("register")),
+            keeper,
+        );
+
+        Ok(())
+    }
+
+    /// Pay out a keeper's accrued rewards in USDC from the treasury
+    ///
+    /// The treasury must have approved this contract to spend its USDC.
+    ///
+    /// # Returns
+    /// Amount claimed
+    pub fn claim_keeper_rewards(env: Env, keeper: Address) -> Result<i128, RiskError> {
+        keeper.require_auth();
+
+        let amount = Self::get_keeper_rewards(env.clone(), keeper.clone());
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .ok_or(RiskError::KeeperRewardUnavailable)?;
+        let usdc: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::UsdcToken)
+            .ok_or(RiskError::KeeperRewardUnavailable)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::KeeperRewards(keeper.clone()));
+
+        token::Client::new(&env, &usdc)
+            .try_transfer_from(&env.current_contract_address(), &treasury, &keeper, &amount)
+            .map_err(|_| RiskError::KeeperRewardUnavailable)?
+            .map_err(|_| RiskError::KeeperRewardUnavailable)?;
+
+        env.events().publish(
+            (symbol_short!("keeper"), symbol_short!("claimed")),
+            (keeper, amount),
+        );
+
+        Ok(amount)
+    }
+
+    /// Credit a registered keeper with `keeper_reward_bp` of `base_amount`
+    ///
+    /// Returns the credited reward (0 for callers that are not keepers).
+    fn credit_keeper_reward(env: &Env, caller: &Address, base_amount: i128) -> i128 {
+        if !Self::is_keeper(env.clone(), caller.clone()) {
+            return 0;
+        }
+
+        let reward = base_amount * Self::get_keeper_reward(env.clone()) as i128 / 10000;
+        if reward <= 0 {
+            return 0;
+        }
+
+        let accrued = Self::get_keeper_rewards(env.clone(), caller.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::KeeperRewards(caller.clone()), &(accrued + reward));
+
+        reward
+    }
+
+    /// Set the fixed-point scale of health factors (admin only)
+    ///
+    /// `scale` represents a health factor of 1.0 and must be a power of ten
+    /// from 10000 up to 1e12. Thresholds in `RiskParameters` stay in basis
+    /// points and are rescaled when compared; set the same scale on the pool.
+    pub fn set_hf_scale(env: Env, caller: Address, scale: i128) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if !is_valid_hf_scale(scale) {
+            return Err(RiskError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::HfScale, &scale);
+        Ok(())
+    }
+
+    /// Set the keeper reward rate (admin only)
+    ///
+    /// Applied to debt repaid in liquidations.
+    ///
+    /// # Arguments
+    /// * `reward_bp` - Reward in basis points, at most `MAX_KEEPER_REWARD_BP`
+    pub fn set_keeper_reward(env: Env, caller: Address, reward_bp: u32) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if reward_bp > MAX_KEEPER_REWARD_BP {
+            return Err(RiskError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::KeeperRewardBp, &reward_bp);
+        Ok(())
+    }
+
+    /// Get the keeper reward rate (basis points)
+    pub fn get_keeper_reward(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::KeeperRewardBp)
+            .unwrap_or(0)
+    }
+
     /// Calculate how much collateral to swap for stop-loss
     fn calculate_stop_loss_amount(
         env: &Env,
@@ -498,6 +1082,7 @@ impl RiskEngineContract {
         debt_to_repay: i128,
     ) -> Result<LiquidationEvent, RiskError> {
         liquidator.require_auth();
+        Self::require_liquidations_active(&env)?;
 
         let params: RiskParameters = env
             .storage()
@@ -505,28 +1090,170 @@ impl RiskEngineContract {
             .get(&DataKey::RiskParams)
             .unwrap_or_default();
 
-        // Check health factor
-        let health_factor = Self::get_user_health_factor(&env, &user)?;
-
-        if health_factor >= params.liquidation_threshold {
-            return Err(RiskError::NotLiquidatable);
-        }
-
-        // Calculate maximum liquidatable amount
-        let (max_collateral, max_debt) = Self::calculate_max_liquidation(
+        Self::execute_liquidation(
             &env,
-            &user,
+            &liquidator,
+            user,
+            collateral_asset,
+            debt_to_repay,
             &params,
-        )?;
+        )
+    }
 
-        let actual_debt_repay = if debt_to_repay > max_debt {
+    /// Liquidate several positions in one transaction
+    ///
+    /// Each target is `(user, collateral_asset, debt_to_repay)` and is
+    /// processed as in `liquidate`. Targets with no outstanding debt or a
+    /// health factor at or above the liquidation threshold are skipped; any
+    /// other failure aborts the whole batch.
+    ///
+    /// # Returns
+    /// Events for the liquidations that were executed, in target order
+    pub fn liquidate_batch(
+        env: Env,
+        liquidator: Address,
+        targets: Vec<(Address, Address, i128)>,
+    ) -> Result<Vec<LiquidationEvent>, RiskError> {
+        liquidator.require_auth();
+        Self::require_liquidations_active(&env)?;
+
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        let mut events = Vec::new(&env);
+        for (user, collateral_asset, debt_to_repay) in targets.iter() {
+            if Self::get_user_debt(&env, &user)? <= 0 {
+                continue;
+            }
+
+            match Self::execute_liquidation(
+                &env,
+                &liquidator,
+                user,
+                collateral_asset,
+                debt_to_repay,
+                &params,
+            ) {
+                Ok(event) => events.push_back(event),
+                Err(RiskError::NotLiquidatable) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Net profit a liquidator would make on `liquidate` (USD, 14 decimals)
+    ///
+    /// Collateral received minus debt repaid minus the protocol fee, for
+    /// the same clamped amounts `liquidate` would use. Negative when the
+    /// seizable collateral does not cover the repayment and fee. Fails like
+    /// `liquidate` if the position cannot be liquidated.
+    pub fn simulate_liquidation_profit(
+        env: Env,
+        user: Address,
+        collateral_asset: Address,
+        debt_to_repay: i128,
+    ) -> Result<i128, RiskError> {
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        let (collateral, debt, fee, backstop) =
+            Self::plan_liquidation(&env, &user, &collateral_asset, debt_to_repay, &params)?;
+
+        Ok(collateral - debt - fee + backstop)
+    }
+
+    /// Shared body of `liquidate` and `liquidate_batch` (auth already checked)
+    fn execute_liquidation(
+        env: &Env,
+        liquidator: &Address,
+        user: Address,
+        collateral_asset: Address,
+        debt_to_repay: i128,
+        params: &RiskParameters,
+    ) -> Result<LiquidationEvent, RiskError> {
+        let (final_collateral, actual_debt_repay, protocol_fee_amount, backstop_reward) =
+            Self::plan_liquidation(env, &user, &collateral_asset, debt_to_repay, params)?;
+
+        // In production: execute the actual transfers
+        // 1. Transfer USDC from liquidator to pool
+        // 2. Transfer collateral from pool to liquidator
+        // 3. Transfer protocol fee to treasury
+
+        let event = LiquidationEvent {
+            user: user.clone(),
+            liquidator: liquidator.clone(),
+            collateral_asset,
+            collateral_seized: final_collateral,
+            debt_repaid: actual_debt_repay,
+            penalty: final_collateral - actual_debt_repay,
+            protocol_fee: protocol_fee_amount,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        env.events().publish(
+            (symbol_short!("liquidate"), symbol_short!("partial")),
+            (&event.user, event.debt_repaid),
+        );
+
+        Self::credit_keeper_reward(env, liquidator, actual_debt_repay);
+        Self::credit_backstop_reward(env, liquidator, backstop_reward);
+
+        Self::record_liquidation(env, &event);
+
+        Ok(event)
+    }
+
+    /// Collateral seized, debt repaid, protocol fee and backstop reward for a liquidation
+    ///
+    /// Checks the position is liquidatable and clamps `debt_to_repay` to the
+    /// close factor, without changing any state. The protocol fee is already
+    /// reduced by whatever `min_liquidator_reward` took from it.
+    fn plan_liquidation(
+        env: &Env,
+        user: &Address,
+        collateral_asset: &Address,
+        debt_to_repay: i128,
+        params: &RiskParameters,
+    ) -> Result<(i128, i128, i128, i128), RiskError> {
+        // Check health factor
+        let health_factor = Self::get_user_health_factor(env, user)?;
+
+        if health_factor >= Self::liquidation_threshold_scaled(env, params) {
+            return Err(RiskError::NotLiquidatable);
+        }
+
+        let liquidation_penalty = Self::liquidation_penalty(env, collateral_asset, params);
+
+        // Calculate maximum liquidatable amount
+        let (max_collateral, max_debt) = Self::calculate_max_liquidation(
+            env,
+            user,
+            liquidation_penalty,
+        )?;
+
+        let actual_debt_repay = if debt_to_repay > max_debt {
             max_debt
         } else {
             debt_to_repay
         };
 
+        // Dust liquidations are only allowed when they clear the whole debt
+        if actual_debt_repay < params.min_liquidation_debt
+            && actual_debt_repay < Self::get_user_debt(env, user)?
+        {
+            return Err(RiskError::InvalidParams);
+        }
+
         // Calculate collateral to seize (debt + penalty)
-        let penalty_factor = 10000 + params.liquidation_penalty as i128;
+        let penalty_factor = 10000 + liquidation_penalty as i128;
         let collateral_to_seize = actual_debt_repay * penalty_factor / 10000;
 
         // Protocol fee
@@ -539,35 +1266,535 @@ impl RiskEngineContract {
             collateral_to_seize
         };
 
-        // In production: execute the actual transfers
-        // 1. Transfer USDC from liquidator to pool
-        // 2. Transfer collateral from pool to liquidator
-        // 3. Transfer protocol fee to treasury
+        let (_, protocol_fee_amount, backstop_reward) = liquidation::apply_reward_floor(
+            final_collateral - actual_debt_repay - protocol_fee_amount,
+            protocol_fee_amount,
+            params.min_liquidator_reward,
+        );
+
+        Ok((final_collateral, actual_debt_repay, protocol_fee_amount, backstop_reward))
+    }
+
+    /// Credit the part of a liquidator's reward floor the penalty could not cover
+    ///
+    /// Accrues alongside keeper rewards and is paid from the treasury by
+    /// `claim_keeper_rewards`.
+    fn credit_backstop_reward(env: &Env, liquidator: &Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+
+        let accrued = Self::get_keeper_rewards(env.clone(), liquidator.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::KeeperRewards(liquidator.clone()), &(accrued + amount));
+
+        env.events().publish(
+            (symbol_short!("liquidate"), symbol_short!("backstop")),
+            (liquidator, amount),
+        );
+    }
+
+    /// Start a Dutch auction to liquidate an unhealthy position
+    ///
+    /// The discount ramps linearly from `start_discount` to `end_discount`
+    /// over `duration` seconds. Parameters must fall within the admin-set
+    /// `AuctionBounds`. Fails with `AuctionActive` while an earlier auction
+    /// for the user is still open.
+    pub fn start_liquidation_auction(
+        env: Env,
+        caller: Address,
+        user: Address,
+        start_discount: u32,
+        end_discount: u32,
+        duration: u64,
+    ) -> Result<DutchAuctionParams, RiskError> {
+        caller.require_auth();
+        Self::require_liquidations_active(&env)?;
+
+        // Restarting would reset the discount clock and refill a partly sold lot
+        if env.storage().persistent().has(&DataKey::Auction(user.clone())) {
+            return Err(RiskError::AuctionActive);
+        }
+
+        let bounds: AuctionBounds = env
+            .storage()
+            .instance()
+            .get(&DataKey::AuctionBounds)
+            .unwrap_or_default();
+
+        if !bounds.is_valid(start_discount, end_discount, duration) {
+            return Err(RiskError::InvalidParams);
+        }
+
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        // The ramp must outlast the delay or bidders only ever see the end discount
+        if duration <= params.min_auction_delay {
+            return Err(RiskError::InvalidParams);
+        }
+
+        let health_factor = Self::get_user_health_factor(&env, &user)?;
+        let threshold = Self::liquidation_threshold_scaled(&env, &params);
+        if !liquidation::is_liquidatable(health_factor, threshold) {
+            return Err(RiskError::NotLiquidatable);
+        }
+
+        let auction = DutchAuctionParams {
+            start_discount,
+            end_discount,
+            duration,
+            start_time: env.ledger().timestamp(),
+        };
+
+        // Size the lot for the ending discount, the most it can ever pay out
+        let (_, max_debt) =
+            Self::calculate_max_liquidation(&env, &user, params.liquidation_penalty)?;
+        let lot = AuctionLot {
+            bid: max_debt,
+            lot: max_debt * (10000 + end_discount as i128) / 10000,
+            size: max_debt,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Auction(user.clone()), &auction);
+        env.storage()
+            .persistent()
+            .set(&DataKey::AuctionLot(user.clone()), &lot);
+
+        env.events().publish(
+            (symbol_short!("auction"), symbol_short!("started")),
+            (&user, start_discount, end_discount, duration),
+        );
+
+        Ok(auction)
+    }
+
+    /// Fill part or all of an open Dutch auction
+    ///
+    /// Repays up to `max_debt_to_repay` of the remaining bid and releases a
+    /// pro-rata share of the lot at the current discount. The auction stays
+    /// open until the bid is fully repaid; any collateral left in the lot at
+    /// that point stays with the user. Fills are rejected with
+    /// `AuctionNotReady` until `min_auction_delay` has passed since the start.
+    /// A single fill repays at most `get_max_fill_share` of the starting bid.
+    pub fn fill_liquidation_auction(
+        env: Env,
+        liquidator: Address,
+        user: Address,
+        collateral_asset: Address,
+        max_debt_to_repay: i128,
+    ) -> Result<LiquidationEvent, RiskError> {
+        liquidator.require_auth();
+        Self::require_liquidations_active(&env)?;
+
+        if max_debt_to_repay <= 0 {
+            return Err(RiskError::InvalidParams);
+        }
+
+        let auction: DutchAuctionParams = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Auction(user.clone()))
+            .ok_or(RiskError::AuctionNotFound)?;
+        let mut lot: AuctionLot = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AuctionLot(user.clone()))
+            .ok_or(RiskError::AuctionNotFound)?;
+
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        let now = env.ledger().timestamp();
+        if now < auction.start_time + params.min_auction_delay {
+            return Err(RiskError::AuctionNotReady);
+        }
+
+        let max_fill = lot.size * Self::get_max_fill_share(env.clone()) as i128 / 10000;
+        let debt_repaid = max_debt_to_repay.min(max_fill).min(lot.bid);
+        let discount = auction.current_discount(now);
+        let collateral_seized = lot
+            .collateral_for(debt_repaid, discount, auction.end_discount)
+            .min(lot.lot);
+        let (_, protocol_fee, backstop_reward) = liquidation::calculate_liquidation_bonus(
+            collateral_seized,
+            debt_repaid,
+            params.protocol_fee,
+            params.min_liquidator_reward,
+        );
+
+        lot.bid -= debt_repaid;
+        lot.lot -= collateral_seized;
+
+        if lot.bid == 0 {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Auction(user.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::AuctionLot(user.clone()));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::AuctionLot(user.clone()), &lot);
+        }
+
+        // In production: transfer USDC from liquidator and collateral to liquidator
 
         let event = LiquidationEvent {
             user: user.clone(),
-            liquidator: liquidator.clone(),
+            liquidator,
             collateral_asset,
-            collateral_seized: final_collateral,
-            debt_repaid: actual_debt_repay,
-            penalty: final_collateral - actual_debt_repay,
-            protocol_fee: protocol_fee_amount,
+            collateral_seized,
+            debt_repaid,
+            penalty: collateral_seized - debt_repaid,
+            protocol_fee,
             timestamp: env.ledger().timestamp(),
         };
 
         env.events().publish(
-            (symbol_short!("liquidate"), symbol_short!("partial")),
-            (&event.user, event.debt_repaid),
+            (symbol_short!("auction"), symbol_short!("filled")),
+            (&user, debt_repaid, collateral_seized),
         );
 
+        Self::credit_backstop_reward(&env, &event.liquidator, backstop_reward);
+
+        Self::record_liquidation(&env, &event);
+
         Ok(event)
     }
 
+    /// Cancel a user's auction once their position has recovered
+    ///
+    /// Requires the health factor to be back at or above the liquidation
+    /// threshold, then removes the auction and submits a
+    /// `DeleteLiquidationAuction` request through the Blend adapter on the
+    /// user's behalf.
+    pub fn cancel_liquidation_auction(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Auction(user.clone())) {
+            return Err(RiskError::AuctionNotFound);
+        }
+
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        let health_factor = Self::get_user_health_factor(&env, &user)?;
+        let threshold = Self::liquidation_threshold_scaled(&env, &params);
+        if liquidation::is_liquidatable(health_factor, threshold) {
+            return Err(RiskError::PositionStillLiquidatable);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Auction(user.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AuctionLot(user.clone()));
+
+        let blend_adapter: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendAdapter)
+            .ok_or(RiskError::BlendAdapterError)?;
+
+        let requests = Vec::from_array(
+            &env,
+            [liquidation::build_blend_delete_auction_request(user.clone())],
+        );
+        BlendAdapterClient::new(&env, &blend_adapter)
+            .try_submit(&user, &requests)
+            .map_err(|_| RiskError::BlendAdapterError)?
+            .map_err(|_| RiskError::BlendAdapterError)?;
+
+        env.events().publish(
+            (symbol_short!("auction"), symbol_short!("cancelled")),
+            (&user, health_factor),
+        );
+
+        Ok(())
+    }
+
+    /// Set global Dutch auction bounds (admin only)
+    pub fn set_auction_bounds(
+        env: Env,
+        caller: Address,
+        bounds: AuctionBounds,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if bounds.min_duration == 0
+            || bounds.min_duration > bounds.max_duration
+            || bounds.max_end_discount > 10000
+        {
+            return Err(RiskError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::AuctionBounds, &bounds);
+        Ok(())
+    }
+
+    /// Set the maximum share of an auction a single fill may repay (admin only)
+    ///
+    /// # Arguments
+    /// * `share_bp` - Share of the starting bid in basis points, 10000 = no cap
+    pub fn set_max_fill_share(env: Env, caller: Address, share_bp: u32) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if share_bp == 0 || share_bp > 10000 {
+            return Err(RiskError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::MaxFillShareBp, &share_bp);
+        Ok(())
+    }
+
+    /// Get the maximum share of an auction a single fill may repay (basis points)
+    pub fn get_max_fill_share(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxFillShareBp)
+            .unwrap_or(10000)
+    }
+
+    /// Enable or disable market stress mode (admin only)
+    ///
+    /// While enabled, `threshold_delta_bp` is added to the liquidation
+    /// threshold used by all liquidation checks.
+    pub fn set_stress_mode(
+        env: Env,
+        caller: Address,
+        enabled: bool,
+        threshold_delta_bp: u32,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if threshold_delta_bp > 10000 {
+            return Err(RiskError::InvalidParams);
+        }
+
+        let stress = StressMode {
+            enabled,
+            threshold_delta_bp,
+        };
+        env.storage().instance().set(&DataKey::StressMode, &stress);
+
+        env.events().publish(
+            (symbol_short!("stress"), symbol_short!("updated")),
+            (enabled, threshold_delta_bp),
+        );
+
+        Ok(())
+    }
+
+    /// Halt liquidations, auctions and stop-losses (admin only)
+    ///
+    /// Meant for oracle outages, when liquidating on bad prices would seize
+    /// collateral from healthy positions. Affected calls fail with `Paused`.
+    pub fn pause_liquidations(env: Env, caller: Address) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        Self::set_liquidations_paused(&env, true);
+        Ok(())
+    }
+
+    /// Resume liquidations, auctions and stop-losses (admin only)
+    ///
+    /// Stop-losses resume immediately; liquidations and auctions stay
+    /// frozen for `get_liquidation_grace_period` seconds so borrowers can
+    /// repay or top up first.
+    pub fn unpause_liquidations(env: Env, caller: Address) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        // An oversized grace period only keeps liquidations frozen; it must
+        // never make unpausing itself fail
+        let resume_at = env
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::get_liquidation_grace_period(env.clone()));
+        env.storage()
+            .instance()
+            .set(&DataKey::LiquidationsResumeAt, &resume_at);
+
+        Self::set_liquidations_paused(&env, false);
+        Ok(())
+    }
+
+    /// Set how long liquidations stay frozen after an unpause (admin only)
+    pub fn set_liquidation_grace_period(
+        env: Env,
+        caller: Address,
+        grace_period: u64,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::LiquidationGracePeriod, &grace_period);
+        Ok(())
+    }
+
+    /// Get how long liquidations stay frozen after an unpause (seconds)
+    pub fn get_liquidation_grace_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LiquidationGracePeriod)
+            .unwrap_or(0)
+    }
+
+    /// Whether a position can be liquidated right now
+    ///
+    /// Always `false` while liquidations are paused or inside the grace
+    /// period after an unpause, so a frozen position is never reported as
+    /// liquidatable.
+    pub fn is_liquidatable(env: Env, user: Address) -> Result<bool, RiskError> {
+        if Self::liquidations_frozen(&env) {
+            return Ok(false);
+        }
+
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+
+        let health_factor = Self::get_user_health_factor(&env, &user)?;
+        Ok(liquidation::is_liquidatable(
+            health_factor,
+            Self::liquidation_threshold_scaled(&env, &params),
+        ))
+    }
+
+    /// Whether liquidations and stop-losses are paused
+    pub fn is_liquidations_paused(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::LiquidationsPaused)
+            .unwrap_or(false)
+    }
+
+    fn set_liquidations_paused(env: &Env, paused: bool) {
+        env.storage().instance().set(&DataKey::LiquidationsPaused, &paused);
+
+        env.events().publish((symbol_short!("liq"), symbol_short!("paused")), paused);
+    }
+
+    /// Whether liquidations are paused or still inside the post-unpause grace period
+    fn liquidations_frozen(env: &Env) -> bool {
+        let resume_at: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LiquidationsResumeAt)
+            .unwrap_or(0);
+
+        Self::is_liquidations_paused(env.clone()) || env.ledger().timestamp() < resume_at
+    }
+
+    fn require_liquidations_active(env: &Env) -> Result<(), RiskError> {
+        if Self::liquidations_frozen(env) {
+            return Err(RiskError::Paused);
+        }
+        Ok(())
+    }
+
+    /// Effective liquidation threshold at the health-factor scale
+    fn liquidation_threshold_scaled(env: &Env, params: &RiskParameters) -> i128 {
+        Self::to_hf_scale(env, Self::effective_liquidation_threshold(env, params))
+    }
+
+    /// Convert a basis-point threshold to the configured health-factor scale
+    fn to_hf_scale(env: &Env, value_bp: i128) -> i128 {
+        rescale_hf(value_bp, DEFAULT_HF_SCALE, Self::get_hf_scale(env.clone()))
+    }
+
+    /// Liquidation threshold after applying any active stress override
+    fn effective_liquidation_threshold(env: &Env, params: &RiskParameters) -> i128 {
+        let stress: StressMode = env
+            .storage()
+            .instance()
+            .get(&DataKey::StressMode)
+            .unwrap_or_default();
+
+        if stress.enabled {
+            params.liquidation_threshold + stress.threshold_delta_bp as i128
+        } else {
+            params.liquidation_threshold
+        }
+    }
+
+    /// Set how many liquidations each history keeps (admin only)
+    pub fn set_liquidation_history_cap(
+        env: Env,
+        caller: Address,
+        cap: u32,
+    ) -> Result<(), RiskError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if cap == 0 {
+            return Err(RiskError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::LiquidationHistoryCap, &cap);
+        Ok(())
+    }
+
+    /// Append a liquidation to the user and global ring buffers
+    fn record_liquidation(env: &Env, event: &LiquidationEvent) {
+        let cap: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::LiquidationHistoryCap)
+            .unwrap_or(DEFAULT_LIQUIDATION_HISTORY_CAP);
+
+        for key in [
+            DataKey::LiquidationHistory(event.user.clone()),
+            DataKey::GlobalLiquidationHistory,
+        ] {
+            let mut history: Vec<LiquidationEvent> = env
+                .storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(Vec::new(env));
+
+            history.push_back(event.clone());
+            while history.len() > cap {
+                history.pop_front();
+            }
+
+            env.storage().persistent().set(&key, &history);
+        }
+    }
+
     /// Calculate maximum liquidation amounts for a user
     fn calculate_max_liquidation(
         _env: &Env,
         _user: &Address,
-        params: &RiskParameters,
+        liquidation_penalty: u32,
     ) -> Result<(i128, i128), RiskError> {
         // In production: get actual values from pool
         // For now, return placeholder values
@@ -575,13 +1802,48 @@ impl RiskEngineContract {
         // Calculate minimum amount to reach target health factor
         let max_collateral = 1000_0000000i128; // Placeholder
         let max_debt = max_collateral * 10000
-            / (10000 + params.liquidation_penalty as i128);
+            / (10000 + liquidation_penalty as i128);
 
         Ok((max_collateral, max_debt))
     }
 
+    /// Liquidation penalty for a collateral asset (basis points)
+    ///
+    /// Uses the pool's per-asset `CollateralConfig.liquidation_penalty`,
+    /// falling back to the global `RiskParameters.liquidation_penalty` when
+    /// the pool has no config for the asset or cannot be queried.
+    fn liquidation_penalty(env: &Env, asset: &Address, params: &RiskParameters) -> u32 {
+        let pool: Option<Address> = env.storage().instance().get(&DataKey::Pool);
+
+        let config = match pool {
+            Some(pool) => PoolClient::new(env, &pool).try_get_collateral_config(asset),
+            None => return params.liquidation_penalty,
+        };
+
+        match config {
+            Ok(Ok(Some(config))) => config.liquidation_penalty,
+            _ => params.liquidation_penalty,
+        }
+    }
+
     // ============ Health Monitoring ============
 
+    /// Get a user's outstanding debt (principal plus interest) from the pool
+    fn get_user_debt(env: &Env, user: &Address) -> Result<i128, RiskError> {
+        let pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Pool)
+            .ok_or(RiskError::PoolError)?;
+
+        let borrow = PoolClient::new(env, &pool)
+            .try_get_borrow(user)
+            .map_err(|_| RiskError::PoolError)?
+            .map_err(|_| RiskError::PoolError)?;
+
+        Ok(borrow.principal + borrow.accrued_interest)
+    }
+
     /// Get user's current health factor from Blend adapter
     fn get_user_health_factor(env: &Env, user: &Address) -> Result<i128, RiskError> {
         // Get Blend adapter address
@@ -598,7 +1860,7 @@ impl RiskEngineContract {
 
         // Placeholder: return healthy
         // In production: return health_result.health_factor
-        Ok(11000) // 1.1
+        Ok(Self::to_hf_scale(env, 11000)) // 1.1
     }
 
     /// Query health factor from Blend adapter
@@ -621,6 +1883,56 @@ impl RiskEngineContract {
         })
     }
 
+    /// Get the uniform collateral price drop that would make a position liquidatable
+    ///
+    /// Solves `weighted_collateral × (1 - drop) / debt = liquidation_threshold`
+    /// using the pool's liquidation-threshold-weighted collateral, so debt is
+    /// assumed to be in a stable asset.
+    ///
+    /// # Returns
+    /// Price drop in basis points (0 if already liquidatable, 10000 if no debt)
+    pub fn liquidation_price_drop(env: Env, user: Address) -> Result<u32, RiskError> {
+        let pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Pool)
+            .ok_or(RiskError::PoolError)?;
+        let pool_client = PoolClient::new(&env, &pool);
+
+        let borrow = match pool_client.try_get_borrow(&user) {
+            Ok(Ok(data)) => data,
+            _ => return Err(RiskError::PoolError),
+        };
+        let debt = borrow.principal + borrow.accrued_interest;
+        if debt <= 0 {
+            return Ok(10000);
+        }
+
+        let breakdown = match pool_client.try_get_collateral_breakdown(&user) {
+            Ok(Ok(breakdown)) => breakdown,
+            _ => return Err(RiskError::PoolError),
+        };
+        let weighted_collateral: i128 = breakdown.iter().map(|(_, _, _, weighted)| weighted).sum();
+        if weighted_collateral <= 0 {
+            return Ok(0);
+        }
+
+        let params: RiskParameters = env
+            .storage()
+            .instance()
+            .get(&DataKey::RiskParams)
+            .unwrap_or_default();
+        let threshold = Self::effective_liquidation_threshold(&env, &params);
+
+        // Remaining fraction of collateral value at which HF hits the threshold
+        let remaining = threshold * debt / weighted_collateral;
+        if remaining >= 10000 {
+            return Ok(0);
+        }
+
+        Ok((10000 - remaining) as u32)
+    }
+
     /// Check if a position needs attention
     pub fn check_position_health(
         env: Env,
@@ -634,19 +1946,52 @@ impl RiskEngineContract {
 
         let health_factor = Self::get_user_health_factor(&env, &user)?;
 
-        let status = if health_factor >= 11000 {
+        // Checked first so a stress override can flag otherwise healthy positions
+        let status = if health_factor < Self::liquidation_threshold_scaled(&env, &params) {
+            symbol_short!("liquidate")
+        } else if health_factor >= Self::to_hf_scale(&env, 11000) {
             symbol_short!("healthy")
-        } else if health_factor >= params.stop_loss_threshold {
+        } else if health_factor >= Self::to_hf_scale(&env, params.stop_loss_threshold) {
             symbol_short!("warning")
-        } else if health_factor >= params.liquidation_threshold {
-            symbol_short!("critical")
         } else {
-            symbol_short!("liquidate")
+            symbol_short!("critical")
         };
 
         Ok((health_factor, status))
     }
 
+    /// Get a user's worst-case health factor across the pool and Blend
+    ///
+    /// Either venue can liquidate the user, so this is the lower of the
+    /// pool's health factor and the Blend adapter's, both at `get_hf_scale`
+    /// (`i128::MAX` when neither has debt).
+    pub fn get_combined_health(env: Env, user: Address) -> Result<i128, RiskError> {
+        let pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Pool)
+            .ok_or(RiskError::PoolError)?;
+        let pool_health = PoolClient::new(&env, &pool)
+            .try_get_health_factor(&user)
+            .map_err(|_| RiskError::PoolError)?
+            .map_err(|_| RiskError::PoolError)?;
+
+        let blend_adapter: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendAdapter)
+            .ok_or(RiskError::BlendAdapterError)?;
+        let blend_health = BlendAdapterClient::new(&env, &blend_adapter)
+            .try_get_health_factor(&user)
+            .map_err(|_| RiskError::BlendAdapterError)?
+            .map_err(|_| RiskError::BlendAdapterError)?;
+
+        // The adapter always reports in basis points
+        let blend_health = Self::to_hf_scale(&env, blend_health.health_factor);
+
+        Ok(pool_health.min(blend_health))
+    }
+
     // ============ View Functions ============
 
     /// Get admin address
@@ -665,6 +2010,56 @@ impl RiskEngineContract {
             .unwrap_or_default()
     }
 
+    /// Get global Dutch auction bounds
+    pub fn get_auction_bounds(env: Env) -> AuctionBounds {
+        env.storage()
+            .instance()
+            .get(&DataKey::AuctionBounds)
+            .unwrap_or_default()
+    }
+
+    /// Get a user's recent liquidations, oldest first
+    pub fn get_liquidation_history(env: Env, user: Address) -> Vec<LiquidationEvent> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LiquidationHistory(user))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get recent liquidations across all users, oldest first
+    pub fn get_global_liquidation_history(env: Env) -> Vec<LiquidationEvent> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GlobalLiquidationHistory)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the remaining bid and lot of a user's active auction
+    pub fn get_auction_lot(env: Env, user: Address) -> Option<AuctionLot> {
+        env.storage().persistent().get(&DataKey::AuctionLot(user))
+    }
+
+    /// Get the fixed-point scale of health factors (10000 = basis points)
+    pub fn get_hf_scale(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::HfScale)
+            .unwrap_or(DEFAULT_HF_SCALE)
+    }
+
+    /// Get market stress mode settings
+    pub fn get_stress_mode(env: Env) -> StressMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::StressMode)
+            .unwrap_or_default()
+    }
+
+    /// Get a user's active liquidation auction
+    pub fn get_auction(env: Env, user: Address) -> Option<DutchAuctionParams> {
+        env.storage().persistent().get(&DataKey::Auction(user))
+    }
+
     /// Get user's stop-loss config
     pub fn get_stop_loss_config(env: Env, user: Address) -> Option<UserStopLossConfig> {
         env.storage()
@@ -672,6 +2067,22 @@ impl RiskEngineContract {
             .get(&DataKey::StopLoss(user))
     }
 
+    /// Get a keeper's unclaimed rewards (USDC)
+    pub fn get_keeper_rewards(env: Env, keeper: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::KeeperRewards(keeper))
+            .unwrap_or(0)
+    }
+
+    /// Check if an address is a registered keeper
+    pub fn is_keeper(env: Env, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Keeper(address))
+            .unwrap_or(false)
+    }
+
     /// Check if address is a whitelisted liquidator
     pub fn is_liquidator(env: Env, address: Address) -> bool {
         let liquidators: Vec<Address> = env
@@ -38,7 +38,7 @@ pub struct LiquidationResult {
 
 /// Dutch auction parameters for liquidation
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DutchAuctionParams {
     /// Starting discount (basis points)
     pub start_discount: u32,
@@ -71,6 +71,70 @@ impl DutchAuctionParams {
     }
 }
 
+/// Remaining debt and collateral of an open Dutch auction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionLot {
+    /// Debt still to be repaid by fillers
+    pub bid: i128,
+    /// Collateral still available, sized for the ending discount
+    pub lot: i128,
+    /// Bid at the start of the auction, the base for per-fill caps
+    pub size: i128,
+}
+
+impl AuctionLot {
+    /// Collateral released for repaying `repay` of the remaining bid
+    ///
+    /// The lot is shared out pro rata to the bid, scaled by how far the
+    /// discount has ramped: fills at the ending discount receive their full
+    /// share, earlier fills receive proportionally less.
+    pub fn collateral_for(&self, repay: i128, current_discount: u32, end_discount: u32) -> i128 {
+        if self.bid <= 0 {
+            return 0;
+        }
+
+        self.lot * repay * (10000 + current_discount as i128)
+            / (self.bid * (10000 + end_discount as i128))
+    }
+}
+
+/// Global bounds applied when starting a Dutch auction
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AuctionBounds {
+    /// Minimum auction duration (seconds)
+    pub min_duration: u64,
+    /// Maximum auction duration (seconds)
+    pub max_duration: u64,
+    /// Maximum ending discount (basis points)
+    pub max_end_discount: u32,
+}
+
+impl Default for AuctionBounds {
+    fn default() -> Self {
+        Self {
+            min_duration: 60,        // 1 minute
+            max_duration: 86400,     // 1 day
+            max_end_discount: 2000,  // 20%
+        }
+    }
+}
+
+impl AuctionBounds {
+    /// Check that auction parameters fall within the bounds
+    ///
+    /// The discount must also ramp upward (start <= end), otherwise
+    /// `current_discount` would underflow.
+    pub fn is_valid(&self, start_discount: u32, end_discount: u32, duration: u64) -> bool {
+        duration > 0
+            && duration >= self.min_duration
+            && duration <= self.max_duration
+            && start_discount <= end_discount
+            && end_discount <= self.max_end_discount
+    }
+}
+
 /// Calculate the minimum liquidation amount to restore target health
 ///
 /// # Arguments
@@ -141,24 +205,44 @@ pub fn calculate_partial_liquidation(
 /// * `collateral_seized` - Amount of collateral seized
 /// * `debt_repaid` - Amount of debt repaid
 /// * `protocol_fee_bp` - Protocol fee in basis points
+/// * `min_liquidator_reward` - Smallest bonus the liquidator receives
 ///
 /// # Returns
-/// (liquidator_bonus, protocol_fee)
+/// (liquidator_bonus, protocol_fee, backstop_reward), see `apply_reward_floor`
 pub fn calculate_liquidation_bonus(
     collateral_seized: i128,
     debt_repaid: i128,
     protocol_fee_bp: u32,
-) -> (i128, i128) {
-    let total_bonus = collateral_seized - debt_repaid;
-
-    if total_bonus <= 0 {
-        return (0, 0);
-    }
+    min_liquidator_reward: i128,
+) -> (i128, i128, i128) {
+    let total_bonus = (collateral_seized - debt_repaid).max(0);
 
     let protocol_fee = total_bonus * protocol_fee_bp as i128 / 10000;
     let liquidator_bonus = total_bonus - protocol_fee;
 
-    (liquidator_bonus, protocol_fee)
+    apply_reward_floor(liquidator_bonus, protocol_fee, min_liquidator_reward)
+}
+
+/// Top a liquidator's bonus up to `min_reward`
+///
+/// The shortfall is taken from the protocol fee first; whatever the fee
+/// cannot cover is returned as a backstop reward paid by the treasury, on
+/// top of the bonus paid out of seized collateral.
+///
+/// # Returns
+/// (liquidator_bonus, protocol_fee, backstop_reward)
+pub fn apply_reward_floor(
+    liquidator_bonus: i128,
+    protocol_fee: i128,
+    min_reward: i128,
+) -> (i128, i128, i128) {
+    let shortfall = min_reward - liquidator_bonus.max(0);
+    if shortfall <= 0 {
+        return (liquidator_bonus, protocol_fee, 0);
+    }
+
+    let from_fee = shortfall.min(protocol_fee.max(0));
+    (liquidator_bonus + from_fee, protocol_fee - from_fee, shortfall - from_fee)
 }
 
 /// Check if a position is liquidatable
@@ -210,10 +294,33 @@ pub fn build_blend_liquidation_request(
     }
 }
 
+/// Build a Blend request deleting a user's liquidation auction
+///
+/// Blend ignores the address and amount for this request type; the user's
+/// address is passed for traceability.
+pub fn build_blend_delete_auction_request(user: Address) -> vantis_types::Request {
+    vantis_types::Request {
+        request_type: RequestType::DeleteLiquidationAuction,
+        address: user,
+        amount: 0,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_auction_bounds_validation() {
+        let bounds = AuctionBounds::default();
+
+        assert!(bounds.is_valid(0, 1000, 3600));
+        assert!(!bounds.is_valid(0, 1000, 0));        // zero duration
+        assert!(!bounds.is_valid(0, 1000, 86401));    // too long
+        assert!(!bounds.is_valid(0, 2001, 3600));     // discount over cap
+        assert!(!bounds.is_valid(1500, 1000, 3600));  // discount decreasing
+    }
+
     #[test]
     fn test_partial_liquidation_calculation() {
         // Position: 950 collateral, 1000 debt, HF = 0.95 (just below 1.0)
@@ -250,15 +357,26 @@ mod tests {
     #[test]
     fn test_liquidation_bonus() {
         // 1050 collateral seized for 1000 debt = 50 bonus
-        let (liquidator, protocol) = calculate_liquidation_bonus(
+        let (liquidator, protocol, backstop) = calculate_liquidation_bonus(
             1050,   // collateral seized
             1000,   // debt repaid
             2000,   // 20% protocol fee
+            0,      // no reward floor
         );
 
         assert_eq!(liquidator + protocol, 50);
         assert_eq!(protocol, 10); // 20% of 50
         assert_eq!(liquidator, 40); // remaining 80%
+        assert_eq!(backstop, 0);
+    }
+
+    #[test]
+    fn test_liquidation_bonus_reward_floor() {
+        // 40 bonus + 10 fee: a floor of 45 is covered by the fee
+        assert_eq!(calculate_liquidation_bonus(1050, 1000, 2000, 45), (45, 5, 0));
+
+        // A floor of 60 takes the whole fee and 10 from the backstop
+        assert_eq!(calculate_liquidation_bonus(1050, 1000, 2000, 60), (50, 0, 10));
     }
 
     #[test]
@@ -291,6 +409,21 @@ mod tests {
         assert_eq!(auction.current_discount(10000), 500); // capped at max
     }
 
+    #[test]
+    fn test_auction_lot_pro_rata() {
+        let lot = AuctionLot {
+            bid: 1000,
+            lot: 1050,
+            size: 1000,
+        };
+
+        // At the ending discount a 40% fill receives 40% of the lot
+        assert_eq!(lot.collateral_for(400, 500, 500), 420);
+
+        // At the start of the ramp the same fill receives less
+        assert_eq!(lot.collateral_for(400, 0, 500), 400);
+    }
+
     #[test]
     fn test_max_single_liquidation() {
         // 50% close factor
@@ -0,0 +1,54 @@
+//! Oracle adapter interface used for asset configuration and volatility
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol, Vec};
+
+/// Asset configuration returned by the oracle
+///
+/// Mirrors `oracle_adapter::AssetConfig`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AssetConfig {
+    /// Asset symbol
+    pub symbol: Symbol,
+    /// Asset contract address on Stellar
+    pub contract: Address,
+    /// Decimals for the asset
+    pub decimals: u32,
+    /// Base LTV (basis points)
+    pub base_ltv: u32,
+    /// Liquidation threshold (basis points)
+    pub liquidation_threshold: u32,
+    /// Maximum price age in seconds (0 = global threshold)
+    pub staleness_threshold: u64,
+    /// Decimals the pushed price feed reports in
+    pub feed_decimals: u32,
+}
+
+/// Volatility data returned by the oracle
+///
+/// Mirrors `oracle_adapter::VolatilityData`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct VolatilityData {
+    /// 30-day historical volatility (annualized, basis points)
+    pub volatility_30d: u32,
+    /// 7-day historical volatility
+    pub volatility_7d: u32,
+    /// Exponentially-weighted volatility (annualized, basis points)
+    pub volatility_ewma: u32,
+    /// Last update timestamp
+    pub last_updated: u64,
+    /// Recent price history
+    pub price_history: Vec<i128>,
+}
+
+/// Subset of the oracle adapter interface used by the risk engine
+///
+/// Only the generated `OracleClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "OracleClient")]
+pub trait OracleInterface {
+    fn get_assets(env: Env) -> Vec<Symbol>;
+    fn get_asset_config(env: Env, asset: Symbol) -> AssetConfig;
+    fn get_volatility(env: Env, asset: Symbol) -> VolatilityData;
+}
@@ -0,0 +1,80 @@
+//! Vantis pool interface used for position queries
+
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol, Vec};
+
+/// Borrow position returned by the pool
+///
+/// Mirrors `vantis_pool::BorrowData` so the risk engine can decode responses
+/// without linking the pool contract crate.
+#[contracttype]
+#[derive(Clone, Debug, Default)]
+pub struct BorrowData {
+    /// Principal borrowed
+    pub principal: i128,
+    /// Accrued interest
+    pub accrued_interest: i128,
+    /// Last interest accrual timestamp
+    pub last_accrual: u64,
+}
+
+/// Collateral asset configuration returned by the pool
+///
+/// Mirrors `vantis_pool::CollateralConfig`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CollateralConfig {
+    /// Token contract address
+    pub token: Address,
+    /// Asset symbol for oracle lookup
+    pub symbol: Symbol,
+    /// Collateral factor (basis points)
+    pub collateral_factor: u32,
+    /// Liquidation threshold (basis points)
+    pub liquidation_threshold: u32,
+    /// Liquidation penalty (basis points)
+    pub liquidation_penalty: u32,
+    /// Is active for deposits
+    pub is_active: bool,
+    /// Token decimals
+    pub decimals: u32,
+    /// Optional phase-in of the collateral factor
+    pub ramp: CollateralRamp,
+}
+
+/// Mirrors `vantis_pool::CollateralRamp`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub enum CollateralRamp {
+    /// The configured collateral factor applies as-is
+    Disabled,
+    /// The factor follows a linear ramp
+    Linear(CollateralFactorRamp),
+}
+
+/// Mirrors `vantis_pool::CollateralFactorRamp`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CollateralFactorRamp {
+    /// Collateral factor at `start_time` (basis points)
+    pub start_factor: u32,
+    /// Collateral factor once the ramp completes (basis points)
+    pub end_factor: u32,
+    /// Ramp start timestamp
+    pub start_time: u64,
+    /// Ramp duration in seconds
+    pub duration: u64,
+}
+
+/// Subset of the Vantis pool interface used by the risk engine
+///
+/// Only the generated `PoolClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "PoolClient")]
+pub trait PoolInterface {
+    fn get_borrow(env: Env, user: Address) -> BorrowData;
+    fn get_interest_rate(env: Env) -> u32;
+    fn get_collateral_breakdown(env: Env, user: Address) -> Vec<(Address, i128, i128, i128)>;
+    fn get_collateral_config(env: Env, asset: Address) -> Option<CollateralConfig>;
+    fn get_borrow_capacity(env: Env, user: Address) -> i128;
+    fn get_health_factor(env: Env, user: Address) -> i128;
+}
@@ -0,0 +1,13 @@
+//! Swap router interface used for stop-loss quotes
+
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/// Subset of the Soroswap-style router interface used by the risk engine
+///
+/// Only the generated `RouterClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "RouterClient")]
+pub trait RouterInterface {
+    /// Expected amounts along `path`, starting with `amount_in`
+    fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128>;
+}
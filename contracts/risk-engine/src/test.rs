@@ -1,7 +1,196 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, vec, Env};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Env,
+};
+
+/// Mock Vantis pool returning a configurable position
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn set_position(env: Env, principal: i128, collateral_value: i128, rate: u32) {
+        env.storage().instance().set(&symbol_short!("principal"), &principal);
+        env.storage().instance().set(&symbol_short!("coll"), &collateral_value);
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn set_user_principal(env: Env, user: Address, principal: i128) {
+        env.storage().instance().set(&(symbol_short!("principal"), user), &principal);
+    }
+
+    pub fn get_borrow(env: Env, user: Address) -> pool::BorrowData {
+        let principal = env
+            .storage()
+            .instance()
+            .get(&(symbol_short!("principal"), user))
+            .or_else(|| env.storage().instance().get(&symbol_short!("principal")))
+            .unwrap_or(0);
+        pool::BorrowData {
+            principal,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_interest_rate(env: Env) -> u32 {
+        env.storage().instance().get(&symbol_short!("rate")).unwrap_or(0)
+    }
+
+    pub fn get_collateral_breakdown(env: Env, _user: Address) -> Vec<(Address, i128, i128, i128)> {
+        let value: i128 = env.storage().instance().get(&symbol_short!("coll")).unwrap_or(0);
+        vec![&env, (env.current_contract_address(), value, value, value * 8000 / 10000)]
+    }
+
+    pub fn get_borrow_capacity(env: Env, user: Address) -> i128 {
+        let value: i128 = env.storage().instance().get(&symbol_short!("coll")).unwrap_or(0);
+        let debt = Self::get_borrow(env, user).principal;
+        (value * 7500 / 10000 - debt).max(0)
+    }
+
+    pub fn set_penalty(env: Env, asset: Address, liquidation_penalty: u32) {
+        env.storage().instance().set(&asset, &liquidation_penalty);
+    }
+
+    pub fn get_collateral_config(env: Env, asset: Address) -> Option<pool::CollateralConfig> {
+        let liquidation_penalty: u32 = env.storage().instance().get(&asset)?;
+        Some(pool::CollateralConfig {
+            token: asset,
+            symbol: symbol_short!("MOCK"),
+            collateral_factor: 7500,
+            liquidation_threshold: 8000,
+            liquidation_penalty,
+            is_active: true,
+            decimals: 7,
+            ramp: pool::CollateralRamp::Disabled,
+        })
+    }
+
+    pub fn set_health_factor(env: Env, health_factor: i128) {
+        env.storage().instance().set(&symbol_short!("hf"), &health_factor);
+    }
+
+    pub fn get_health_factor(env: Env, _user: Address) -> i128 {
+        env.storage().instance().get(&symbol_short!("hf")).unwrap_or(i128::MAX)
+    }
+}
+
+/// Mock Blend adapter recording the last submitted requests
+#[contract]
+pub struct MockAdapter;
+
+#[contractimpl]
+impl MockAdapter {
+    pub fn submit(env: Env, _user: Address, requests: Vec<vantis_types::Request>) {
+        env.storage().instance().set(&symbol_short!("requests"), &requests);
+    }
+
+    pub fn last_requests(env: Env) -> Vec<vantis_types::Request> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("requests"))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+/// Mock Blend adapter reporting a configurable health factor
+///
+/// Kept in its own module so its `get_health_factor` does not clash with `MockPool`'s.
+mod blend_health {
+    use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+    #[contract]
+    pub struct MockBlendHealth;
+
+    #[contractimpl]
+    impl MockBlendHealth {
+        pub fn set_health_factor(env: Env, health_factor: i128) {
+            env.storage().instance().set(&symbol_short!("hf"), &health_factor);
+        }
+
+        pub fn get_health_factor(env: Env, _user: Address) -> vantis_types::HealthFactorResult {
+            let health_factor =
+                env.storage().instance().get(&symbol_short!("hf")).unwrap_or(i128::MAX);
+            vantis_types::HealthFactorResult {
+                health_factor,
+                total_collateral: 0,
+                total_liabilities: 0,
+                is_liquidatable: health_factor < 10000,
+            }
+        }
+    }
+}
+
+/// Mock oracle adapter serving configurable asset configs and volatility
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_asset(env: Env, asset: Symbol, base_ltv: u32, volatility: u32) {
+        let mut assets: Vec<Symbol> = Self::get_assets(env.clone());
+        assets.push_back(asset.clone());
+        env.storage().instance().set(&symbol_short!("assets"), &assets);
+        env.storage()
+            .instance()
+            .set(&(symbol_short!("ltv"), asset.clone()), &base_ltv);
+        env.storage().instance().set(&(symbol_short!("vol"), asset), &volatility);
+    }
+
+    pub fn get_assets(env: Env) -> Vec<Symbol> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("assets"))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    pub fn get_asset_config(env: Env, asset: Symbol) -> oracle::AssetConfig {
+        oracle::AssetConfig {
+            symbol: asset.clone(),
+            contract: env.current_contract_address(),
+            decimals: 7,
+            base_ltv: env.storage().instance().get(&(symbol_short!("ltv"), asset)).unwrap(),
+            liquidation_threshold: 8000,
+            staleness_threshold: 0,
+            feed_decimals: 14,
+        }
+    }
+
+    pub fn get_volatility(env: Env, asset: Symbol) -> oracle::VolatilityData {
+        let volatility: u32 = env.storage().instance().get(&(symbol_short!("vol"), asset)).unwrap();
+        oracle::VolatilityData {
+            volatility_30d: volatility,
+            volatility_7d: volatility,
+            volatility_ewma: volatility,
+            last_updated: env.ledger().timestamp(),
+            price_history: Vec::new(&env),
+        }
+    }
+}
+
+/// Mock swap router quoting at a fixed rate (basis points of the input)
+#[contract]
+pub struct MockRouter;
+
+#[contractimpl]
+impl MockRouter {
+    pub fn set_rate(env: Env, rate_bp: i128) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate_bp);
+    }
+
+    pub fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128> {
+        let rate_bp: i128 = env.storage().instance().get(&symbol_short!("rate")).unwrap();
+        let mut amounts = vec![&env, amount_in];
+        for _ in 1..path.len() {
+            let last = amounts.last().unwrap();
+            amounts.push_back(last * rate_bp / 10000);
+        }
+        amounts
+    }
+}
 
 #[test]
 fn test_initialize() {
@@ -26,6 +215,10 @@ fn test_initialize() {
         liquidation_penalty: 500,
         protocol_fee: 100,
         min_collateral_factor: 3000,
+        max_allowed_slippage: 1000,
+        min_liquidation_debt: 10_0000000,
+        min_auction_delay: 300,
+        min_liquidator_reward: 0,
     };
 
     client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
@@ -41,6 +234,54 @@ fn test_initialize() {
     assert_eq!(stored_adapter, blend_adapter);
 }
 
+#[test]
+fn test_initialize_rejects_duplicate_addresses() {
+    let env = Env::default();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+
+    // Oracle wired where the USDC token belongs
+    let result = client.try_initialize(
+        &admin,
+        &oracle,
+        &pool,
+        &oracle,
+        &blend_adapter,
+        &RiskParameters::default(),
+    );
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    assert!(client.try_admin().is_err());
+}
+
+#[test]
+fn test_initialize_validates_params() {
+    let env = Env::default();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+
+    // Slippage above 100% is refused here just as in update_params
+    let params = RiskParameters {
+        max_allowed_slippage: 10001,
+        ..RiskParameters::default()
+    };
+    let result = client.try_initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    assert!(client.try_admin().is_err());
+}
+
 #[test]
 fn test_update_params() {
     let env = Env::default();
@@ -96,6 +337,7 @@ fn test_enable_disable_stop_loss() {
         custom_threshold: 10300,  // 1.03
         swap_priority: vec![&env],
         max_slippage: 100,  // 1%
+        allow_permissionless: true,
     };
 
     client.enable_stop_loss(&user, &config);
@@ -112,6 +354,198 @@ fn test_enable_disable_stop_loss() {
     assert!(stored.is_none());
 }
 
+#[test]
+fn test_stop_loss_fires_once_without_keeper_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    let params = RiskParameters {
+        target_health_factor: 12000,
+        ..RiskParameters::default()
+    };
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Custom thresholds must sit between liquidation and the target
+    let mut config = UserStopLossConfig {
+        enabled: true,
+        custom_threshold: 10000,
+        swap_priority: vec![&env],
+        max_slippage: 100,
+        allow_permissionless: true,
+    };
+    let result = client.try_enable_stop_loss(&user, &config);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    config.custom_threshold = 12000;
+    let result = client.try_enable_stop_loss(&user, &config);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+
+    // Trigger at HF 1.15 so the placeholder HF of 1.1 is in the stop-loss zone
+    config.custom_threshold = 11500;
+    client.enable_stop_loss(&user, &config);
+
+    // Reward is capped at 5%
+    let result = client.try_set_keeper_reward(&admin, &501);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    client.set_keeper_reward(&admin, &500);
+    client.register_keeper(&keeper);
+    assert!(client.is_keeper(&keeper));
+
+    // The placeholder swap has no proceeds, so even a keeper earns nothing
+    let recovered = client.trigger_stop_loss(&keeper, &user);
+    assert_eq!(recovered, 1800);
+    assert_eq!(client.get_keeper_rewards(&keeper), 0);
+
+    // The stop-loss is spent until the user re-enables it
+    assert!(!client.get_stop_loss_config(&user).unwrap().enabled);
+    let result = client.try_trigger_stop_loss(&keeper, &user);
+    assert_eq!(result, Err(Ok(RiskError::StopLossNotEnabled)));
+
+    client.enable_stop_loss(&user, &config);
+    assert_eq!(client.trigger_stop_loss(&keeper, &user), 1800);
+}
+
+#[test]
+fn test_stop_loss_opt_out_of_permissionless_trigger() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+
+    let params = RiskParameters {
+        target_health_factor: 12000,
+        ..RiskParameters::default()
+    };
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    client.register_keeper(&keeper);
+    client.enable_stop_loss(
+        &user,
+        &UserStopLossConfig {
+            enabled: true,
+            custom_threshold: 11500,
+            swap_priority: vec![&env],
+            max_slippage: 100,
+            allow_permissionless: false,
+        },
+    );
+
+    let result = client.try_trigger_stop_loss(&keeper, &user);
+    assert_eq!(result, Err(Ok(RiskError::Unauthorized)));
+
+    // The user can still execute their own stop-loss
+    assert_eq!(client.trigger_stop_loss(&user, &user), 1800);
+}
+
+#[test]
+fn test_keeper_rewards_accrue_and_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let blend_adapter = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let user = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_position(&50_0000000, &100_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+    client.set_keeper_reward(&admin, &100);
+    client.register_keeper(&keeper);
+
+    // Nothing to claim yet
+    assert_eq!(client.claim_keeper_rewards(&keeper), 0);
+
+    // Make the placeholder HF of 1.1 liquidatable; 1% of the 10 USDC repaid
+    client.set_stress_mode(&admin, &true, &1500);
+    client.liquidate(&keeper, &user, &collateral, &10_0000000);
+    assert_eq!(client.get_keeper_rewards(&keeper), 1000000);
+
+    // Claims fail until a treasury is configured
+    let result = client.try_claim_keeper_rewards(&keeper);
+    assert_eq!(result, Err(Ok(RiskError::KeeperRewardUnavailable)));
+
+    // Treasury funds rewards through an allowance to the risk engine
+    client.set_treasury(&admin, &treasury);
+    token::StellarAssetClient::new(&env, &usdc).mint(&treasury, &5000000);
+    token::Client::new(&env, &usdc).approve(&treasury, &contract_id, &5000000, &1000);
+
+    assert_eq!(client.claim_keeper_rewards(&keeper), 1000000);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&keeper), 1000000);
+    assert_eq!(token::Client::new(&env, &usdc).balance(&treasury), 4000000);
+    assert_eq!(client.get_keeper_rewards(&keeper), 0);
+}
+
+#[test]
+fn test_raise_max_allowed_slippage() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    let config = UserStopLossConfig {
+        enabled: true,
+        custom_threshold: 10300,
+        swap_priority: vec![&env],
+        max_slippage: 1500, // 15%, above the default 10% ceiling
+        allow_permissionless: true,
+    };
+
+    let result = client.try_enable_stop_loss(&user, &config);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+
+    // Admin raises the ceiling for a more volatile market
+    let new_params = RiskParameters {
+        max_allowed_slippage: 2000, // 20%
+        ..params
+    };
+    client.update_params(&admin, &new_params);
+
+    client.enable_stop_loss(&user, &config);
+
+    let stored = client.get_stop_loss_config(&user).unwrap();
+    assert_eq!(stored.max_slippage, 1500);
+}
+
 #[test]
 fn test_add_liquidator() {
     let env = Env::default();
@@ -138,7 +572,7 @@ fn test_add_liquidator() {
 }
 
 #[test]
-fn test_calculate_safe_borrow() {
+fn test_start_auction_rejects_zero_duration() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -150,33 +584,19 @@ fn test_calculate_safe_borrow() {
     let pool = Address::generate(&env);
     let usdc = Address::generate(&env);
     let blend_adapter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let user = Address::generate(&env);
 
-    let params = RiskParameters {
-        k_factor: 100,
-        time_horizon_days: 30,
-        min_collateral_factor: 3000,
-        ..RiskParameters::default()
-    };
+    let params = RiskParameters::default();
     client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
 
-    // Calculate safe borrow
-    let collateral_value = 1000_0000000i128; // 1000 USD
-    let base_ltv = 7500; // 75%
-
-    let safe_borrow = client.calculate_safe_borrow(
-        &symbol_short!("XLM"),
-        &collateral_value,
-        &base_ltv,
-    );
-
-    // With volatility adjustment, safe borrow should be <= 75% of collateral
-    let max_borrow = collateral_value * 7500 / 10000;
-    assert!(safe_borrow <= max_borrow);
-    assert!(safe_borrow > 0);
+    let result = client.try_start_liquidation_auction(&keeper, &user, &0, &1000, &0);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    assert!(client.get_auction(&user).is_none());
 }
 
 #[test]
-fn test_check_position_health() {
+fn test_start_auction_rejects_discount_over_cap() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -188,21 +608,28 @@ fn test_check_position_health() {
     let pool = Address::generate(&env);
     let usdc = Address::generate(&env);
     let blend_adapter = Address::generate(&env);
+    let keeper = Address::generate(&env);
     let user = Address::generate(&env);
 
     let params = RiskParameters::default();
     client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
 
-    // Check position (uses placeholder that returns healthy)
-    let (health, status) = client.check_position_health(&user);
+    // Tighten the discount cap to 10%
+    let bounds = AuctionBounds {
+        min_duration: 60,
+        max_duration: 3600,
+        max_end_discount: 1000,
+    };
+    client.set_auction_bounds(&admin, &bounds);
+    assert_eq!(client.get_auction_bounds().max_end_discount, 1000);
 
-    // Placeholder returns 11000 (healthy)
-    assert_eq!(health, 11000);
-    assert_eq!(status, symbol_short!("healthy"));
+    let result = client.try_start_liquidation_auction(&keeper, &user, &0, &1500, &600);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    assert!(client.get_auction(&user).is_none());
 }
 
 #[test]
-fn test_blend_adapter_integration() {
+fn test_stress_mode_tightens_liquidation_threshold() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -214,35 +641,1054 @@ fn test_blend_adapter_integration() {
     let pool = Address::generate(&env);
     let usdc = Address::generate(&env);
     let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
 
     let params = RiskParameters::default();
     client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
 
-    // Verify blend adapter is stored
-    let stored_adapter = client.get_blend_adapter();
-    assert_eq!(stored_adapter, blend_adapter);
+    // HF 1.1 is safe against the normal 1.0 threshold
+    let result = client.try_liquidate(&liquidator, &user, &collateral, &100_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::NotLiquidatable)));
+
+    // Only admin can toggle stress mode
+    let result = client.try_set_stress_mode(&liquidator, &true, &1500);
+    assert_eq!(result, Err(Ok(RiskError::Unauthorized)));
+
+    // Stress mode raises the threshold to 1.15, so HF 1.1 becomes liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+    assert_eq!(
+        client.get_stress_mode(),
+        StressMode {
+            enabled: true,
+            threshold_delta_bp: 1500,
+        }
+    );
+    let event = client.liquidate(&liquidator, &user, &collateral, &100_0000000);
+    assert_eq!(event.debt_repaid, 100_0000000);
+    let (_, status) = client.check_position_health(&user);
+    assert_eq!(status, symbol_short!("liquidate"));
+
+    // Disabling stress mode restores the normal threshold
+    client.set_stress_mode(&admin, &false, &1500);
+    let result = client.try_liquidate(&liquidator, &user, &collateral, &100_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::NotLiquidatable)));
+}
 
-    // Update blend adapter
-    let new_blend_adapter = Address::generate(&env);
-    client.set_blend_adapter(&admin, &new_blend_adapter);
+#[test]
+fn test_min_liquidation_debt() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    let updated_adapter = client.get_blend_adapter();
-    assert_eq!(updated_adapter, new_blend_adapter);
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_position(&50_0000000, &100_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+    assert_eq!(client.get_params().min_liquidation_debt, 10_0000000);
+
+    // Make the placeholder HF of 1.1 liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+
+    // 5 USDC of a 50 USDC debt is below the 10 USDC minimum
+    let result = client.try_liquidate(&liquidator, &user, &collateral, &5_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::InvalidParams)));
+
+    // At the minimum it goes through
+    let event = client.liquidate(&liquidator, &user, &collateral, &10_0000000);
+    assert_eq!(event.debt_repaid, 10_0000000);
+
+    // A dust repayment that closes the whole debt is allowed
+    pool_client.set_position(&5_0000000, &100_0000000, &500);
+    let event = client.liquidate(&liquidator, &user, &collateral, &5_0000000);
+    assert_eq!(event.debt_repaid, 5_0000000);
 }
 
-// Test volatility module
-mod volatility_tests {
-    use super::volatility::*;
+#[test]
+fn test_pause_blocks_liquidations_and_stop_loss() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    #[test]
-    fn test_volatility_adjusted_ltv() {
-        // Base 75%, 50% volatility, 1% k, 30 days
-        let adjusted = calculate_adjusted_ltv(7500, 5000, 100, 30, 3000);
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
 
-        // Should be reduced from base
-        assert!(adjusted < 7500);
-        // Should be above minimum
-        assert!(adjusted >= 3000);
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_position(&50_0000000, &100_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+    client.enable_stop_loss(
+        &user,
+        &UserStopLossConfig {
+            enabled: true,
+            custom_threshold: 10300,
+            swap_priority: vec![&env],
+            max_slippage: 100,
+            allow_permissionless: true,
+        },
+    );
+
+    // Only the admin can pause
+    let result = client.try_pause_liquidations(&liquidator);
+    assert_eq!(result, Err(Ok(RiskError::Unauthorized)));
+
+    client.pause_liquidations(&admin);
+    assert!(client.is_liquidations_paused());
+
+    // Make the placeholder HF of 1.1 liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+
+    let result = client.try_liquidate(&liquidator, &user, &collateral, &10_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::Paused)));
+    let targets = vec![&env, (user.clone(), collateral.clone(), 10_0000000)];
+    let result = client.try_liquidate_batch(&liquidator, &targets);
+    assert_eq!(result.err(), Some(Ok(RiskError::Paused)));
+    let result = client.try_trigger_stop_loss(&liquidator, &user);
+    assert_eq!(result.err(), Some(Ok(RiskError::Paused)));
+
+    client.unpause_liquidations(&admin);
+    assert!(!client.is_liquidations_paused());
+
+    let event = client.liquidate(&liquidator, &user, &collateral, &10_0000000);
+    assert_eq!(event.debt_repaid, 10_0000000);
+}
+
+#[test]
+fn test_combined_health_takes_riskier_venue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    let blend_adapter = env.register(blend_health::MockBlendHealth, ());
+    let adapter_client = blend_health::MockBlendHealthClient::new(&env, &blend_adapter);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    // No debt anywhere
+    assert_eq!(client.get_combined_health(&user), i128::MAX);
+
+    // Healthy in the pool, underwater on Blend
+    pool_client.set_health_factor(&15000);
+    adapter_client.set_health_factor(&9500);
+    assert_eq!(client.get_combined_health(&user), 9500);
+
+    // And the other way round, at a finer health-factor scale
+    client.set_hf_scale(&admin, &1_000_000);
+    pool_client.set_health_factor(&980_000);
+    adapter_client.set_health_factor(&12000);
+    assert_eq!(client.get_combined_health(&user), 980_000);
+}
+
+#[test]
+fn test_liquidation_grace_period_after_unpause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_position(&50_0000000, &100_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+    client.set_liquidation_grace_period(&admin, &3600);
+
+    // Make the placeholder HF of 1.1 liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+    assert!(client.is_liquidatable(&user));
+
+    // Paused: the position is protected
+    client.pause_liquidations(&admin);
+    assert!(!client.is_liquidatable(&user));
+
+    // Unpaused but inside the grace period: still protected
+    client.unpause_liquidations(&admin);
+    assert!(!client.is_liquidatable(&user));
+    let result = client.try_liquidate(&liquidator, &user, &collateral, &10_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::Paused)));
+
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+    assert!(client.is_liquidatable(&user));
+    let event = client.liquidate(&liquidator, &user, &collateral, &10_0000000);
+    assert_eq!(event.debt_repaid, 10_0000000);
+
+    // An oversized grace period cannot make unpausing fail
+    client.set_liquidation_grace_period(&admin, &u64::MAX);
+    client.pause_liquidations(&admin);
+    client.unpause_liquidations(&admin);
+    assert!(!client.is_liquidatable(&user));
+}
+
+#[test]
+fn test_min_liquidator_reward_floor_on_small_position() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_position(&5_0000000, &100_0000000, &500);
+
+    let params = RiskParameters {
+        min_liquidator_reward: 1_0000000,
+        ..RiskParameters::default()
+    };
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Make the placeholder HF of 1.1 liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+
+    // On 5 USDC the 5% penalty less the 1% fee is only 0.2 USDC; the floor
+    // takes the 0.05 fee and 0.75 from the backstop
+    let profit = client.simulate_liquidation_profit(&user, &collateral, &5_0000000);
+    assert_eq!(profit, 1_0000000);
+
+    let event = client.liquidate(&liquidator, &user, &collateral, &5_0000000);
+    assert_eq!(event.protocol_fee, 0);
+    assert_eq!(event.collateral_seized - event.debt_repaid, 2500000);
+    assert_eq!(client.get_keeper_rewards(&liquidator), 7500000);
+
+    // A negative floor is rejected
+    let params = RiskParameters {
+        min_liquidator_reward: -1,
+        ..RiskParameters::default()
+    };
+    let result = client.try_update_params(&admin, &params);
+    assert_eq!(result.err(), Some(Ok(RiskError::InvalidParams)));
+}
+
+#[test]
+fn test_liquidate_batch_skips_healthy_targets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let underwater_a = Address::generate(&env);
+    let underwater_b = Address::generate(&env);
+    let debt_free = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_user_principal(&underwater_a, &100_0000000);
+    pool_client.set_user_principal(&underwater_b, &40_0000000);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    let targets = vec![
+        &env,
+        (underwater_a.clone(), collateral.clone(), 50_0000000),
+        (debt_free.clone(), collateral.clone(), 50_0000000),
+        (underwater_b.clone(), collateral.clone(), 20_0000000),
+    ];
+
+    // Placeholder HF of 1.1 is above the normal threshold: nothing to do
+    assert_eq!(client.liquidate_batch(&liquidator, &targets).len(), 0);
+
+    // Under stress mode only the indebted positions are liquidated
+    client.set_stress_mode(&admin, &true, &1500);
+    let events = client.liquidate_batch(&liquidator, &targets);
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.get(0).unwrap().user, underwater_a);
+    assert_eq!(events.get(0).unwrap().debt_repaid, 50_0000000);
+    assert_eq!(events.get(1).unwrap().user, underwater_b);
+    assert_eq!(events.get(1).unwrap().debt_repaid, 20_0000000);
+
+    assert_eq!(client.get_liquidation_history(&underwater_a).len(), 1);
+    assert_eq!(client.get_liquidation_history(&debt_free).len(), 0);
+}
+
+#[test]
+fn test_per_asset_liquidation_penalty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let stable = Address::generate(&env);
+    let volatile = Address::generate(&env);
+    let unlisted = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_penalty(&stable, &300);
+    pool_client.set_penalty(&volatile, &1500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+    client.set_stress_mode(&admin, &true, &1500);
+
+    let event = client.liquidate(&liquidator, &user, &stable, &100_0000000);
+    assert_eq!(event.collateral_seized, 103_0000000);
+
+    let event = client.liquidate(&liquidator, &user, &volatile, &100_0000000);
+    assert_eq!(event.collateral_seized, 115_0000000);
+    assert_eq!(event.penalty, 15_0000000);
+
+    // Assets without a pool config use the global 5% penalty
+    let event = client.liquidate(&liquidator, &user, &unlisted, &100_0000000);
+    assert_eq!(event.collateral_seized, 105_0000000);
+}
+
+#[test]
+fn test_simulate_liquidation_profit_matches_liquidation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let thin = Address::generate(&env);
+    let unlisted = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_penalty(&thin, &50);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    let result = client.try_simulate_liquidation_profit(&user, &unlisted, &100_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::NotLiquidatable)));
+
+    client.set_stress_mode(&admin, &true, &1500);
+
+    // 5% penalty less the 1% protocol fee on 100 repaid
+    let profit = client.simulate_liquidation_profit(&user, &unlisted, &100_0000000);
+    assert_eq!(profit, 4_0000000);
+    assert!(client.get_liquidation_history(&user).is_empty());
+
+    let event = client.liquidate(&liquidator, &user, &unlisted, &100_0000000);
+    assert_eq!(profit, event.collateral_seized - event.debt_repaid - event.protocol_fee);
+
+    // A 0.5% penalty does not cover the 1% fee
+    let profit = client.simulate_liquidation_profit(&user, &thin, &100_0000000);
+    assert_eq!(profit, -5000000);
+
+    let event = client.liquidate(&liquidator, &user, &thin, &100_0000000);
+    assert_eq!(profit, event.collateral_seized - event.debt_repaid - event.protocol_fee);
+}
+
+#[test]
+fn test_liquidation_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let other_user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Raise the threshold above the placeholder HF so positions are liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+
+    client.liquidate(&liquidator, &user, &collateral, &100_0000000);
+    client.liquidate(&liquidator, &user, &collateral, &50_0000000);
+
+    let history = client.get_liquidation_history(&user);
+    assert_eq!(history.len(), 2);
+    assert_eq!(history.get(0).unwrap().debt_repaid, 100_0000000);
+    assert_eq!(history.get(1).unwrap().debt_repaid, 50_0000000);
+    assert_eq!(history.get(1).unwrap().liquidator, liquidator);
+
+    // Cap of 2: a third liquidation evicts the oldest global entry
+    client.set_liquidation_history_cap(&admin, &2);
+    client.liquidate(&liquidator, &other_user, &collateral, &25_0000000);
+
+    let global = client.get_global_liquidation_history();
+    assert_eq!(global.len(), 2);
+    assert_eq!(global.get(0).unwrap().debt_repaid, 50_0000000);
+    assert_eq!(global.get(1).unwrap().user, other_user);
+    assert_eq!(client.get_liquidation_history(&user).len(), 2);
+    assert_eq!(client.get_liquidation_history(&other_user).len(), 1);
+
+    let result = client.try_set_liquidation_history_cap(&admin, &0);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+}
+
+#[test]
+fn test_partial_auction_fills() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Raise the threshold above the placeholder HF so the position is liquidatable
+    client.set_stress_mode(&admin, &true, &1500);
+    client.start_liquidation_auction(&keeper, &user, &0, &500, &3600);
+
+    // Placeholder max debt: 1000 / 1.05; lot sized for the 5% ending discount
+    let initial = client.get_auction_lot(&user).unwrap();
+    assert_eq!(initial.bid, 952_3809523);
+    assert_eq!(initial.lot, 999_9999999);
+
+    // Fill at the ending discount
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+
+    // 40% of the bid receives 40% of the lot
+    let first_repay = initial.bid * 4 / 10;
+    let first = client.fill_liquidation_auction(&liquidator, &user, &collateral, &first_repay);
+    assert_eq!(first.debt_repaid, 380_9523809);
+    assert_eq!(first.collateral_seized, 399_9999999);
+
+    let remaining = client.get_auction_lot(&user).unwrap();
+    assert_eq!(remaining.bid, initial.bid - first_repay);
+    assert_eq!(remaining.lot, 600_0000000);
+    assert!(client.get_auction(&user).is_some());
+
+    // A running auction cannot be restarted to reset its clock and lot
+    let result = client.try_start_liquidation_auction(&keeper, &user, &0, &500, &3600);
+    assert_eq!(result, Err(Ok(RiskError::AuctionActive)));
+    assert_eq!(client.get_auction_lot(&user).unwrap(), remaining);
+
+    // Offering more than the remaining 60% only repays the remainder
+    let second = client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    assert_eq!(second.debt_repaid, remaining.bid);
+    assert_eq!(second.collateral_seized, remaining.lot);
+
+    // Lot exhausted, auction closed
+    assert!(client.get_auction(&user).is_none());
+    assert!(client.get_auction_lot(&user).is_none());
+    let result = client.try_fill_liquidation_auction(&liquidator, &user, &collateral, &1);
+    assert_eq!(result.err(), Some(Ok(RiskError::AuctionNotFound)));
+    assert_eq!(client.get_liquidation_history(&user).len(), 2);
+
+    // Once closed, a new auction can start
+    client.start_liquidation_auction(&keeper, &user, &0, &500, &3600);
+}
+
+#[test]
+fn test_auction_fill_capped_per_filler() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    assert_eq!(client.get_max_fill_share(), 10000);
+    let result = client.try_set_max_fill_share(&admin, &10001);
+    assert_eq!(result.err(), Some(Ok(RiskError::InvalidParams)));
+    client.set_max_fill_share(&admin, &2500);
+
+    client.set_stress_mode(&admin, &true, &1500);
+    client.start_liquidation_auction(&keeper, &user, &0, &500, &3600);
+    let initial = client.get_auction_lot(&user).unwrap();
+    env.ledger().with_mut(|li| li.timestamp += 3600);
+
+    // Offering the whole bid only repays 25% of it
+    let first = client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    assert_eq!(first.debt_repaid, initial.bid / 4);
+
+    // The cap stays anchored to the starting bid, not the remainder
+    let second = client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    assert_eq!(second.debt_repaid, initial.bid / 4);
+    assert_eq!(
+        client.get_auction_lot(&user).unwrap().bid,
+        initial.bid - initial.bid / 4 * 2
+    );
+
+    // Four capped fills leave only the rounding remainder
+    client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    let remaining = client.get_auction_lot(&user).unwrap();
+    assert_eq!(remaining.bid, initial.bid % 4);
+
+    let last = client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    assert_eq!(last.debt_repaid, remaining.bid);
+    assert!(client.get_auction_lot(&user).is_none());
+}
+
+#[test]
+fn test_auction_fill_waits_for_min_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let params = RiskParameters {
+        min_auction_delay: 600,
+        ..RiskParameters::default()
+    };
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+    client.set_stress_mode(&admin, &true, &1500);
+
+    // The ramp must be longer than the delay
+    let result = client.try_start_liquidation_auction(&keeper, &user, &0, &500, &600);
+    assert_eq!(result.err(), Some(Ok(RiskError::InvalidParams)));
+
+    client.start_liquidation_auction(&keeper, &user, &0, &500, &3600);
+    let initial = client.get_auction_lot(&user).unwrap();
+
+    // Sniping inside the delay is rejected
+    env.ledger().with_mut(|li| li.timestamp += 300);
+    let result = client.try_fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    assert_eq!(result.err(), Some(Ok(RiskError::AuctionNotReady)));
+
+    // Halfway through the ramp the discount has reached 2.5%
+    env.ledger().with_mut(|li| li.timestamp += 1500);
+    let fill = client.fill_liquidation_auction(&liquidator, &user, &collateral, &initial.bid);
+    assert_eq!(fill.debt_repaid, initial.bid);
+    assert_eq!(fill.collateral_seized, initial.lot * 10250 / 10500);
+}
+
+#[test]
+fn test_cancel_auction_after_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let keeper = Address::generate(&env);
+    let liquidator = Address::generate(&env);
+    let user = Address::generate(&env);
+    let collateral = Address::generate(&env);
+
+    let blend_adapter = env.register(MockAdapter, ());
+    let adapter_client = MockAdapterClient::new(&env, &blend_adapter);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Position is liquidatable under stress and an auction starts
+    client.set_stress_mode(&admin, &true, &1500);
+    client.start_liquidation_auction(&keeper, &user, &0, &500, &3600);
+
+    // Cannot cancel while the position is still underwater
+    let result = client.try_cancel_liquidation_auction(&keeper, &user);
+    assert_eq!(result, Err(Ok(RiskError::PositionStillLiquidatable)));
+
+    // Price recovers: the normal threshold applies again
+    client.set_stress_mode(&admin, &false, &0);
+    client.cancel_liquidation_auction(&keeper, &user);
+
+    assert!(client.get_auction(&user).is_none());
+    let requests = adapter_client.last_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests.get(0).unwrap().request_type,
+        vantis_types::RequestType::DeleteLiquidationAuction
+    );
+
+    let result = client.try_fill_liquidation_auction(&liquidator, &user, &collateral, &100_0000000);
+    assert_eq!(result.err(), Some(Ok(RiskError::AuctionNotFound)));
+}
+
+#[test]
+fn test_calculate_safe_borrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+
+    let params = RiskParameters {
+        k_factor: 100,
+        time_horizon_days: 30,
+        min_collateral_factor: 3000,
+        ..RiskParameters::default()
+    };
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Calculate safe borrow
+    let collateral_value = 1000_0000000i128; // 1000 USD
+    let base_ltv = 7500; // 75%
+
+    let safe_borrow = client.calculate_safe_borrow(
+        &symbol_short!("XLM"),
+        &collateral_value,
+        &base_ltv,
+    );
+
+    // With volatility adjustment, safe borrow should be <= 75% of collateral
+    let max_borrow = collateral_value * 7500 / 10000;
+    assert!(safe_borrow <= max_borrow);
+    assert!(safe_borrow > 0);
+}
+
+#[test]
+fn test_safe_borrow_in_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    let collateral_value = 1000_00000000000000i128; // 1000 USD (14 decimals)
+    let base_ltv = 7500;
+
+    let safe_borrow_usd = client.calculate_safe_borrow(&symbol_short!("XLM"), &collateral_value, &base_ltv);
+    let safe_borrow_usdc =
+        client.safe_borrow_in_token(&symbol_short!("XLM"), &collateral_value, &base_ltv, &6);
+
+    // 14-decimal USD scaled down to 6-decimal USDC units
+    assert_eq!(safe_borrow_usdc, safe_borrow_usd / 100_000_000);
+    assert!(safe_borrow_usdc > 0);
+    assert!(safe_borrow_usdc <= 750_000000); // at most 750 USDC
+}
+
+#[test]
+fn test_effective_rate_negative_with_high_yield() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    // 500 USDC borrowed at 5% against 1000 USDC of collateral
+    pool_client.set_position(&500_0000000, &1000_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    // Without collateral yield the effective rate is the borrow rate
+    assert_eq!(client.get_effective_rate(&user), 500);
+
+    // 8% yield on 2x the principal outweighs the 5% borrow cost:
+    // (500 * 5% - 1000 * 8%) / 500 = -11%
+    client.set_collateral_yield_rate(&admin, &800);
+    assert_eq!(client.get_effective_rate(&user), -1100);
+}
+
+#[test]
+fn test_borrow_capacity_matches_pool() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    pool_client.set_position(&500_0000000, &1000_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    assert_eq!(client.get_borrow_capacity(&user), pool_client.get_borrow_capacity(&user));
+    assert_eq!(client.get_borrow_capacity(&user), 250_0000000);
+
+    // Without a reachable pool there is no capacity to report
+    let unwired = env.register(RiskEngineContract, ());
+    let unwired_client = RiskEngineContractClient::new(&env, &unwired);
+    unwired_client.initialize(
+        &admin,
+        &oracle,
+        &Address::generate(&env),
+        &usdc,
+        &blend_adapter,
+        &RiskParameters::default(),
+    );
+    let result = unwired_client.try_get_borrow_capacity(&user);
+    assert_eq!(result, Err(Ok(RiskError::PoolError)));
+}
+
+#[test]
+fn test_swap_quote_through_router() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let xlm = Address::generate(&env);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    // No router configured yet
+    let result = client.try_get_swap_quote(&xlm, &100_0000000);
+    assert_eq!(result, Err(Ok(RiskError::SwapFailed)));
+
+    // 1 XLM = 0.12 USDC
+    let router = env.register(MockRouter, ());
+    MockRouterClient::new(&env, &router).set_rate(&1200);
+    client.set_swap_router(&admin, &router);
+
+    assert_eq!(client.get_swap_quote(&xlm, &100_0000000), 12_0000000);
+
+    // USDC needs no swap
+    assert_eq!(client.get_swap_quote(&usdc, &100_0000000), 100_0000000);
+
+    let result = client.try_get_swap_quote(&xlm, &0);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+}
+
+#[test]
+fn test_swap_into_unlisted_token_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let xlm = Address::generate(&env);
+    let btc = Address::generate(&env);
+    let junk = Address::generate(&env);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    let router = env.register(MockRouter, ());
+    MockRouterClient::new(&env, &router).set_rate(&1200);
+    client.set_swap_router(&admin, &router);
+
+    // USDC is always a valid output
+    assert!(client.is_swap_asset_allowed(&usdc));
+    assert_eq!(client.get_swap_quote_to(&xlm, &usdc, &100_0000000), 12_0000000);
+
+    // A route into an unlisted token is refused before reaching the router
+    let result = client.try_get_swap_quote_to(&xlm, &junk, &100_0000000);
+    assert_eq!(result, Err(Ok(RiskError::DisallowedSwapAsset)));
+
+    // Registered collateral can be allowlisted
+    client.allow_swap_asset(&admin, &btc);
+    assert!(client.is_swap_asset_allowed(&btc));
+    assert_eq!(client.get_swap_quote_to(&xlm, &btc, &100_0000000), 12_0000000);
+
+    client.disallow_swap_asset(&admin, &btc);
+    let result = client.try_get_swap_quote_to(&xlm, &btc, &100_0000000);
+    assert_eq!(result, Err(Ok(RiskError::DisallowedSwapAsset)));
+
+    // Only the admin manages the allowlist
+    let result = client.try_allow_swap_asset(&Address::generate(&env), &junk);
+    assert_eq!(result, Err(Ok(RiskError::Unauthorized)));
+}
+
+#[test]
+fn test_ltv_table_penalizes_volatile_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+
+    let oracle = env.register(MockOracle, ());
+    let oracle_client = MockOracleClient::new(&env, &oracle);
+    oracle_client.set_asset(&symbol_short!("XLM"), &7500, &8000);
+    oracle_client.set_asset(&symbol_short!("BTC"), &7500, &3000);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    let table = client.get_ltv_table();
+    assert_eq!(table.len(), 2);
+
+    let xlm = table.get(0).unwrap();
+    let btc = table.get(1).unwrap();
+    assert_eq!(xlm.asset, symbol_short!("XLM"));
+    assert_eq!(btc.asset, symbol_short!("BTC"));
+    assert_eq!(xlm.volatility, 8000);
+    assert_eq!(btc.volatility, 3000);
+
+    // Same base LTV, so the more volatile asset gets the larger haircut
+    assert!(xlm.adjusted_ltv < btc.adjusted_ltv);
+    assert_eq!(xlm.adjusted_ltv, 7479);
+    assert_eq!(btc.adjusted_ltv, 7493);
+}
+
+#[test]
+fn test_liquidation_price_drop() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let pool = env.register(MockPool, ());
+    let pool_client = MockPoolClient::new(&env, &pool);
+    // 1250 USDC of collateral at an 80% threshold = 1000 weighted, 500 debt -> HF 2.0
+    pool_client.set_position(&500_0000000, &1250_0000000, &500);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    // HF 2.0 against a 1.0 threshold: collateral can lose half its value
+    // 1000 × (1 - 50%) / 500 = 1.0
+    assert_eq!(client.liquidation_price_drop(&user), 5000);
+
+    // No debt means no price drop can liquidate the position
+    pool_client.set_position(&0, &1250_0000000, &500);
+    assert_eq!(client.liquidation_price_drop(&user), 10000);
+}
+
+#[test]
+fn test_check_position_health() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Check position (uses placeholder that returns healthy)
+    let (health, status) = client.check_position_health(&user);
+
+    // Placeholder returns 11000 (healthy)
+    assert_eq!(health, 11000);
+    assert_eq!(status, symbol_short!("healthy"));
+}
+
+#[test]
+fn test_check_position_health_under_custom_scale() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &RiskParameters::default());
+
+    let result = client.try_set_hf_scale(&admin, &20000);
+    assert_eq!(result, Err(Ok(RiskError::InvalidParams)));
+    client.set_hf_scale(&admin, &1_000_000);
+    assert_eq!(client.get_hf_scale(), 1_000_000);
+
+    // Placeholder HF of 1.1 is reported at the new scale and still healthy
+    let (health, status) = client.check_position_health(&user);
+    assert_eq!(health, 1_100_000);
+    assert_eq!(status, symbol_short!("healthy"));
+
+    // Basis-point thresholds are rescaled: +1500 bp puts the threshold at 1.15
+    client.set_stress_mode(&admin, &true, &1500);
+    let (_, status) = client.check_position_health(&user);
+    assert_eq!(status, symbol_short!("liquidate"));
+
+    // +500 bp puts the threshold at 1.05, below the placeholder HF
+    client.set_stress_mode(&admin, &true, &500);
+    let (_, status) = client.check_position_health(&user);
+    assert_eq!(status, symbol_short!("healthy"));
+}
+
+#[test]
+fn test_blend_adapter_integration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(RiskEngineContract, ());
+    let client = RiskEngineContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    let oracle = Address::generate(&env);
+    let pool = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let blend_adapter = Address::generate(&env);
+
+    let params = RiskParameters::default();
+    client.initialize(&admin, &oracle, &pool, &usdc, &blend_adapter, &params);
+
+    // Verify blend adapter is stored
+    let stored_adapter = client.get_blend_adapter();
+    assert_eq!(stored_adapter, blend_adapter);
+
+    // Update blend adapter
+    let new_blend_adapter = Address::generate(&env);
+    client.set_blend_adapter(&admin, &new_blend_adapter);
+
+    let updated_adapter = client.get_blend_adapter();
+    assert_eq!(updated_adapter, new_blend_adapter);
+}
+
+// Test volatility module
+mod volatility_tests {
+    use super::volatility::*;
+
+    #[test]
+    fn test_volatility_adjusted_ltv() {
+        // Base 75%, 50% volatility, 1% k, 30 days
+        let adjusted = calculate_adjusted_ltv(7500, 5000, 100, 30, 3000);
+
+        // Should be reduced from base
+        assert!(adjusted < 7500);
+        // Should be above minimum
+        assert!(adjusted >= 3000);
+    }
+
+    #[test]
+    fn test_extreme_volatility_is_non_borrowable() {
+        // 100% volatility with an 80% k over a year: adjustment 8000 > base 7500
+        assert_eq!(calculate_adjusted_ltv(7500, 10000, 8000, 365, 3000), 0);
+
+        // A smaller adjustment that still crosses the floor gets the floor
+        assert_eq!(calculate_adjusted_ltv(7500, 10000, 5000, 365, 3000), 3000);
     }
 
     #[test]
@@ -302,15 +1748,17 @@ mod liquidation_tests {
 
     #[test]
     fn test_liquidation_bonus_split() {
-        let (liquidator, protocol) = calculate_liquidation_bonus(
+        let (liquidator, protocol, backstop) = calculate_liquidation_bonus(
             1050,  // seized
             1000,  // repaid
             2000,  // 20% protocol fee
+            0,     // no reward floor
         );
 
         assert_eq!(liquidator + protocol, 50);
         assert_eq!(protocol, 10);
         assert_eq!(liquidator, 40);
+        assert_eq!(backstop, 0);
     }
 
     #[test]
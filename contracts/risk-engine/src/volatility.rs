@@ -39,7 +39,8 @@ pub struct VolatilityAdjustedLTV {
 /// * `min_ltv` - Minimum LTV floor in basis points
 ///
 /// # Returns
-/// Adjusted LTV in basis points
+/// Adjusted LTV in basis points, or 0 when the adjustment exceeds the base
+/// LTV and the asset is too volatile to borrow against
 pub fn calculate_adjusted_ltv(
     base_ltv: u32,
     volatility: u32,
@@ -56,8 +57,13 @@ pub fn calculate_adjusted_ltv(
     // All values in basis points, so normalize
     let adjustment = (k_factor as i128 * volatility as i128 * sqrt_t) / (1000 * 10000);
 
+    // Too volatile to borrow against at all; the floor must not apply
+    if adjustment > base_ltv as i128 {
+        return 0;
+    }
+
     // Adjusted LTV = base_ltv - adjustment
-    let adjusted = (base_ltv as i128).saturating_sub(adjustment);
+    let adjusted = base_ltv as i128 - adjustment;
 
     // Apply minimum floor
     if adjusted < min_ltv as i128 {
@@ -32,25 +32,59 @@ impl BorrowPosition {
     }
 }
 
+/// Calculate interest accrued over a period at a basis-point rate
+///
+/// The pool accrues through `calculate_interest_precise`; this is its
+/// `rate_decimals = 0` form.
+#[cfg(test)]
+pub fn calculate_interest(principal: i128, rate: u32, time_elapsed: u64) -> i128 {
+    calculate_interest_precise(principal, rate as i128, 0, time_elapsed)
+}
+
 /// Calculate interest accrued over a period
 ///
 /// # Arguments
 /// * `principal` - Principal amount
-/// * `rate` - Annual interest rate in basis points
+/// * `rate` - Annual interest rate in units of `1 / (10000 * 10^rate_decimals)`
+/// * `rate_decimals` - Decimals the rate carries beyond basis points
 /// * `time_elapsed` - Time elapsed in seconds
 ///
 /// # Returns
 /// Interest amount
-pub fn calculate_interest(principal: i128, rate: u32, time_elapsed: u64) -> i128 {
-    if principal <= 0 || rate == 0 || time_elapsed == 0 {
+///
+/// # Overflow
+/// Multiplying a large 14-decimal principal by `rate * time_elapsed` first
+/// can overflow `i128`. The principal is split into a whole multiple of the
+/// denominator and a remainder, so the only product that can exceed the
+/// result is `remainder * rate * time_elapsed`. Rounding matches the naive
+/// formula exactly.
+///
+/// Idle periods long enough to overflow even so saturate at `i128::MAX`
+/// rather than trapping; the pool caps the booked interest relative to the
+/// principal.
+pub fn calculate_interest_precise(
+    principal: i128,
+    rate: i128,
+    rate_decimals: u32,
+    time_elapsed: u64,
+) -> i128 {
+    if principal <= 0 || rate <= 0 || time_elapsed == 0 {
         return 0;
     }
 
     const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
     const BASIS_POINTS: i128 = 10000;
 
-    // interest = principal * rate * time / (seconds_per_year * basis_points)
-    principal * rate as i128 * time_elapsed as i128 / (SECONDS_PER_YEAR as i128 * BASIS_POINTS)
+    // interest = principal * rate * time / (seconds_per_year * basis_points * 10^decimals)
+    let denominator = SECONDS_PER_YEAR as i128 * BASIS_POINTS * 10i128.pow(rate_decimals);
+    let factor = rate * time_elapsed as i128;
+
+    let whole = principal / denominator;
+    let remainder = principal % denominator;
+
+    whole
+        .saturating_mul(factor)
+        .saturating_add(remainder.saturating_mul(factor) / denominator)
 }
 
 /// Calculate utilization rate
@@ -69,6 +103,24 @@ pub fn calculate_utilization(total_borrows: i128, total_liquidity: i128) -> u32
     (total_borrows * 10000 / total_liquidity) as u32
 }
 
+/// Calculate the rate earned by suppliers
+///
+/// Suppliers share the borrow interest pro rata, less the protocol's
+/// reserve-factor cut, so the rate is the borrow rate scaled by utilization
+/// and by `1 - reserve_factor`.
+///
+/// # Arguments
+/// * `borrow_rate` - Current borrow rate in basis points per year
+/// * `utilization` - Current utilization in basis points
+/// * `reserve_factor` - Share of interest kept by the protocol in basis points
+///
+/// # Returns
+/// Supply rate in basis points per year
+pub fn calculate_supply_rate(borrow_rate: u32, utilization: u32, reserve_factor: u32) -> u32 {
+    let gross = borrow_rate as i128 * utilization as i128 / 10000;
+    (gross * (10000 - reserve_factor as i128) / 10000) as u32
+}
+
 /// Calculate interest rate based on utilization (kink model)
 ///
 /// # Arguments
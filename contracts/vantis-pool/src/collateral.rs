@@ -18,6 +18,54 @@ pub struct CollateralPosition {
     pub last_updated: u64,
 }
 
+/// Linear ramp of a collateral factor, used to phase in newly listed assets
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollateralFactorRamp {
+    /// Collateral factor at `start_time` (basis points)
+    pub start_factor: u32,
+    /// Collateral factor once the ramp completes (basis points)
+    pub end_factor: u32,
+    /// Ramp start timestamp
+    pub start_time: u64,
+    /// Ramp duration in seconds
+    pub duration: u64,
+}
+
+impl CollateralFactorRamp {
+    /// Calculate the collateral factor at `current_time`
+    pub fn factor_at(&self, current_time: u64) -> u32 {
+        if current_time < self.start_time {
+            return self.start_factor;
+        }
+
+        let elapsed = current_time - self.start_time;
+        if elapsed >= self.duration {
+            return self.end_factor;
+        }
+
+        // Linear interpolation
+        let progress = elapsed as u128 * 10000 / self.duration as u128;
+        let factor_range = (self.end_factor - self.start_factor) as u128;
+        let additional_factor = factor_range * progress / 10000;
+
+        self.start_factor + additional_factor as u32
+    }
+}
+
+/// Collateral-factor schedule of an asset
+///
+/// Stands in for `Option<CollateralFactorRamp>`, which cannot be stored in a
+/// contract type field.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CollateralRamp {
+    /// The configured collateral factor applies as-is
+    Disabled,
+    /// The factor follows a linear ramp
+    Linear(CollateralFactorRamp),
+}
+
 /// Collateral operation types
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -1,6 +1,7 @@
 //! Health factor calculations and utilities
 
 use soroban_sdk::contracttype;
+use vantis_types::{rescale_hf, DEFAULT_HF_SCALE};
 
 /// Health factor thresholds (in basis points where 10000 = 1.0)
 pub const HEALTH_FACTOR_HEALTHY: i128 = 11000;      // 1.1 - healthy
@@ -23,11 +24,36 @@ pub enum HealthStatus {
     Liquidatable,
 }
 
+impl HealthStatus {
+    /// Classify a health factor (basis points) against the module thresholds
+    pub fn from_health_factor(value: i128) -> Self {
+        Self::from_scaled_health_factor(value, DEFAULT_HF_SCALE)
+    }
+
+    /// Classify a health factor expressed at `scale` (scale = 1.0)
+    ///
+    /// The basis-point thresholds are rescaled rather than the value, so no
+    /// precision is lost at finer scales.
+    pub fn from_scaled_health_factor(value: i128, scale: i128) -> Self {
+        let threshold = |bp: i128| rescale_hf(bp, DEFAULT_HF_SCALE, scale);
+
+        if value >= threshold(HEALTH_FACTOR_HEALTHY) {
+            HealthStatus::Healthy
+        } else if value >= threshold(HEALTH_FACTOR_CRITICAL) {
+            HealthStatus::Warning
+        } else if value >= threshold(HEALTH_FACTOR_LIQUIDATION) {
+            HealthStatus::Critical
+        } else {
+            HealthStatus::Liquidatable
+        }
+    }
+}
+
 /// Health factor data
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct HealthFactor {
-    /// Health factor value (basis points, 10000 = 1.0)
+    /// Health factor value (at the calculation scale, 10000 = 1.0 by default)
     pub value: i128,
     /// Current health status
     pub status: HealthStatus,
@@ -44,24 +70,22 @@ pub struct HealthFactor {
 impl HealthFactor {
     /// Create a new health factor calculation
     pub fn calculate(collateral_value: i128, debt_value: i128) -> Self {
+        Self::calculate_scaled(collateral_value, debt_value, DEFAULT_HF_SCALE)
+    }
+
+    /// Create a health factor calculation with `value` expressed at `scale`
+    pub fn calculate_scaled(collateral_value: i128, debt_value: i128, scale: i128) -> Self {
         let value = if debt_value == 0 {
             i128::MAX
         } else {
-            collateral_value * 10000 / debt_value
+            collateral_value * scale / debt_value
         };
 
-        let status = if value >= HEALTH_FACTOR_HEALTHY {
-            HealthStatus::Healthy
-        } else if value >= HEALTH_FACTOR_CRITICAL {
-            HealthStatus::Warning
-        } else if value >= HEALTH_FACTOR_LIQUIDATION {
-            HealthStatus::Critical
-        } else {
-            HealthStatus::Liquidatable
-        };
+        let status = HealthStatus::from_scaled_health_factor(value, scale);
 
         // Calculate shortfall: how much collateral needed to reach healthy
-        let shortfall = if value < HEALTH_FACTOR_HEALTHY && debt_value > 0 {
+        let healthy = rescale_hf(HEALTH_FACTOR_HEALTHY, DEFAULT_HF_SCALE, scale);
+        let shortfall = if value < healthy && debt_value > 0 {
             // Need: collateral / debt >= 1.1
             // collateral_needed = debt * 1.1 - current_collateral
             let needed = debt_value * HEALTH_FACTOR_HEALTHY / 10000;
@@ -16,17 +16,21 @@
 //! - Position queries use `blend_adapter.get_positions()`
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Map,
-    Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
+    IntoVal, Map, Symbol, Val, Vec,
 };
+use vantis_types::{addresses_distinct, is_valid_hf_scale, scale_decimals, DEFAULT_HF_SCALE};
 
 mod collateral;
 mod borrow;
 mod health;
+mod oracle;
+mod rate_model;
 
-pub use collateral::CollateralPosition;
+pub use collateral::{CollateralFactorRamp, CollateralPosition, CollateralRamp};
 pub use borrow::BorrowPosition;
-pub use health::HealthFactor;
+pub use health::{HealthFactor, HealthStatus};
+pub use oracle::{PegCheck, PriceData};
 
 /// Storage keys
 #[contracttype]
@@ -37,7 +41,7 @@ pub enum DataKey {
     Oracle,
     /// Risk engine contract
     RiskEngine,
-    /// XLM token address
+    /// Pool token: supplied by lenders and lent to borrowers
     XlmToken,
     /// Blend adapter contract address
     BlendPool,
@@ -49,14 +53,62 @@ pub enum DataKey {
     Borrow(Address),
     /// Total deposits per asset
     TotalDeposits(Address),
-    /// Total borrows (USDC)
+    /// Total borrows (pool token)
     TotalBorrows,
-    /// Pool reserves (USDC available to borrow)
+    /// Pool reserves (pool token available to borrow)
     PoolReserves,
     /// Interest rate model parameters
     InterestParams,
+    /// External interest rate model contract (overrides InterestParams)
+    InterestRateModel,
     /// Accrued protocol fees
     ProtocolFees,
+    /// How partial repayments are split between interest and principal
+    RepayOrdering,
+    /// Decimals of the borrow token
+    BorrowDecimals,
+    /// Maximum distinct collateral assets per user
+    MaxUserCollateralAssets,
+    /// Debt-weighted origination rate of a user's borrows (basis points)
+    BorrowRate(Address),
+    /// Unrecoverable debt still counted in total borrows
+    BadDebt,
+    /// Fee charged on each borrow (basis points of the borrowed amount)
+    OriginationFeeBp,
+    /// Whether the origination fee is deducted or added to debt
+    OriginationFeeMode,
+    /// Maximum outstanding borrows of a borrow asset
+    DebtCeiling(Address),
+    /// Fixed-point scale of health factors (scale = 1.0)
+    HfScale,
+    /// Share of accrued interest taken as protocol fees (basis points)
+    ReserveFactor,
+    /// Longest period a single `poke` accrues, in seconds (0 = uncapped)
+    MaxAccrualPeriod,
+    /// Peg band the borrow asset's oracle price must stay within
+    PegCheck,
+    /// Deployment tag prepended to every event's topics
+    InstanceTag,
+    /// Largest health-factor drop a single withdrawal may cause (0 = unlimited)
+    MaxWithdrawalHfDrop,
+    /// Cap on a position's accrued interest (basis points of principal)
+    MaxInterestRatio,
+    /// Borrow assets a collateral asset may back (unset = any)
+    AllowedBorrowAssets(Address),
+    /// Minimum gap between collateral factor and liquidation threshold (basis points)
+    LiquidationBuffer,
+    /// Extra decimals interest rate parameters carry beyond basis points
+    RateDecimals,
+    /// Sequence number of the last published event
+    EventSeq,
+    /// A user's own repayment ordering, overriding the pool's
+    UserRepayOrdering(Address),
+    /// Weighted collateral value a user must exceed to borrow (14-decimal USD)
+    MinCollateralValue,
+    /// Collateral withdrawn from positions but still held by the pool, per asset
+    UnpaidWithdrawals(Address),
+    /// Origination fees booked into protocol fees without tokens changing hands
+    OriginationFees,
 }
 
 /// Collateral asset configuration
@@ -75,8 +127,96 @@ pub struct CollateralConfig {
     pub liquidation_penalty: u32,
     /// Is active for deposits
     pub is_active: bool,
+    /// Token decimals (e.g., 7 for Stellar assets)
+    pub decimals: u32,
+    /// Optional phase-in of the collateral factor; overrides
+    /// `collateral_factor` while `Linear`
+    pub ramp: CollateralRamp,
 }
 
+impl CollateralConfig {
+    /// Collateral factor in effect at `current_time` (basis points)
+    pub fn effective_collateral_factor(&self, current_time: u64) -> u32 {
+        match &self.ramp {
+            CollateralRamp::Linear(ramp) => ramp.factor_at(current_time),
+            CollateralRamp::Disabled => self.collateral_factor,
+        }
+    }
+}
+
+/// Collateral config layout before `decimals` was added
+///
+/// Only used by `migrate_collateral_configs` to read configs persisted
+/// by earlier versions of the contract.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegacyCollateralConfig {
+    /// Token contract address
+    pub token: Address,
+    /// Asset symbol for oracle lookup
+    pub symbol: Symbol,
+    /// Collateral factor (basis points)
+    pub collateral_factor: u32,
+    /// Liquidation threshold (basis points)
+    pub liquidation_threshold: u32,
+    /// Liquidation penalty (basis points)
+    pub liquidation_penalty: u32,
+    /// Is active for deposits
+    pub is_active: bool,
+}
+
+/// Collateral config layout before `ramp` was added
+///
+/// Only used by `migrate_collateral_configs` to read configs persisted
+/// by earlier versions of the contract.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PreRampCollateralConfig {
+    /// Token contract address
+    pub token: Address,
+    /// Asset symbol for oracle lookup
+    pub symbol: Symbol,
+    /// Collateral factor (basis points)
+    pub collateral_factor: u32,
+    /// Liquidation threshold (basis points)
+    pub liquidation_threshold: u32,
+    /// Liquidation penalty (basis points)
+    pub liquidation_penalty: u32,
+    /// Is active for deposits
+    pub is_active: bool,
+    /// Token decimals
+    pub decimals: u32,
+}
+
+/// Default decimals applied to migrated configs (Stellar asset standard)
+const DEFAULT_COLLATERAL_DECIMALS: u32 = 7;
+
+/// Internal accounting precision (matches the 14-decimal oracle prices)
+///
+/// Token amounts are converted to this precision when they enter `deposit`,
+/// `withdraw`, `supply`, `borrow` and `repay`, and converted back to token
+/// units for transfers, events and views. Stored balances, debt, reserves
+/// and USD values therefore share one scale regardless of token decimals.
+pub const ACCOUNTING_DECIMALS: u32 = 14;
+
+/// Default borrow token decimals (Stellar asset standard)
+const DEFAULT_BORROW_DECIMALS: u32 = 7;
+
+/// Default cap on distinct collateral assets per user (bounds the health loop)
+const DEFAULT_MAX_USER_COLLATERAL_ASSETS: u32 = 5;
+
+/// Maximum borrow origination fee (10%)
+const MAX_ORIGINATION_FEE_BP: u32 = 1000;
+
+/// Maximum reserve factor (50% of interest)
+const MAX_RESERVE_FACTOR_BP: u32 = 5000;
+
+/// Default cap on accrued interest (10x principal)
+const DEFAULT_MAX_INTEREST_RATIO_BP: u32 = 100_000;
+
+/// Largest number of extra decimals interest rates may carry beyond basis points
+const MAX_RATE_DECIMALS: u32 = 4;
+
 /// Borrow position for a user
 #[contracttype]
 #[derive(Clone, Debug, Default)]
@@ -89,6 +229,28 @@ pub struct BorrowData {
     pub last_accrual: u64,
 }
 
+/// Order in which a repayment is applied to debt components
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum RepayOrdering {
+    /// Pay off accrued interest before principal
+    #[default]
+    InterestFirst,
+    /// Pay off principal before accrued interest, shrinking the interest base
+    PrincipalFirst,
+}
+
+/// How the borrow origination fee is charged
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OriginationFeeMode {
+    /// Fee is withheld from the borrowed amount; debt equals the amount requested
+    #[default]
+    Deduct,
+    /// Borrower receives the full amount; the fee is added to principal
+    AddToDebt,
+}
+
 /// Interest rate parameters
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -101,6 +263,46 @@ pub struct InterestRateParams {
     pub slope2: u32,
     /// Optimal utilization (basis points)
     pub optimal_utilization: u32,
+    /// Ceiling on the borrow rate (basis points per year)
+    pub max_borrow_rate: u32,
+}
+
+/// Aggregate pool state for dashboards
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PoolState {
+    /// Utilization (basis points, borrows / liquidity)
+    pub utilization: u32,
+    /// Current borrow rate (basis points per year)
+    pub borrow_rate: u32,
+    /// Current supply rate (basis points per year)
+    /// Borrow rate scaled by utilization, net of the reserve factor
+    pub supply_rate: u32,
+    /// USDC available to borrow
+    pub total_reserves: i128,
+    /// Total USDC borrowed
+    pub total_borrows: i128,
+    /// Reserves plus borrows
+    pub total_liquidity: i128,
+}
+
+/// A user's position valued for display
+///
+/// Built by `get_account_summary`, which tolerates stale prices; `stale`
+/// flags that at least one value used an outdated price.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AccountSummary {
+    /// Collateral value (borrow-token units)
+    pub collateral_value: i128,
+    /// Principal plus accrued interest (borrow-token units)
+    pub debt: i128,
+    /// Health factor at `get_hf_scale` (i128::MAX with no debt)
+    pub health_factor: i128,
+    /// Additional amount that could be borrowed (borrow-token units)
+    pub borrow_capacity: i128,
+    /// Whether any collateral was valued at a stale price
+    pub stale: bool,
 }
 
 #[contracterror]
@@ -129,6 +331,30 @@ pub enum PoolError {
     OracleError = 10,
     /// Blend adapter error
     BlendAdapterError = 11,
+    /// Invalid configuration parameters
+    InvalidParams = 12,
+    /// User token balance too low for the transfer
+    InsufficientBalance = 13,
+    /// User already holds the maximum number of distinct collateral assets
+    TooManyCollateralAssets = 14,
+    /// Borrow would push the asset's outstanding debt past its ceiling
+    BorrowCapExceeded = 15,
+    /// Oracle price is older than its staleness threshold
+    StalePrice = 16,
+    /// Oracle price confidence is below the oracle's minimum
+    LowPriceConfidence = 17,
+    /// Oracle has paused the asset's price feed
+    PriceFeedPaused = 18,
+    /// Withdrawal would lower the health factor by more than the per-call limit
+    WithdrawalExceedsHfDrop = 19,
+    /// A held collateral asset may not back the requested borrow asset
+    BorrowAssetNotAllowed = 20,
+    /// Weighted collateral value is at or below the minimum required to borrow
+    CollateralTooSmall = 21,
+    /// Position's collateral still covers its debt
+    PositionSolvent = 22,
+    /// External interest rate model call failed
+    RateModelError = 23,
 }
 
 #[contract]
@@ -141,9 +367,13 @@ impl VantisPoolContract {
     /// # Arguments
     /// * `admin` - Admin address
     /// * `oracle` - Oracle adapter contract address
-    /// * `xlm_token` - XLM token address
+    /// * `xlm_token` - Pool token, supplied by lenders and lent to borrowers
     /// * `blend_pool_address` - Blend adapter contract address
     /// * `interest_params` - Interest rate parameters
+    /// * `instance_tag` - Optional deployment tag prepended to all event topics
+    ///
+    /// # Errors
+    /// - `InvalidParams`: Any two of the addresses are equal
     pub fn initialize(
         env: Env,
         admin: Address,
@@ -151,11 +381,23 @@ impl VantisPoolContract {
         xlm_token: Address,
         blend_pool_address: Address,
         interest_params: InterestRateParams,
-    ) {
+        instance_tag: Option<Symbol>,
+    ) -> Result<(), PoolError> {
         if env.storage().instance().has(&DataKey::Admin) {
             panic!("Already initialized");
         }
 
+        let addresses = vec![
+            &env,
+            admin.clone(),
+            oracle.clone(),
+            xlm_token.clone(),
+            blend_pool_address.clone(),
+        ];
+        if !addresses_distinct(&addresses) {
+            return Err(PoolError::InvalidParams);
+        }
+
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::Oracle, &oracle);
         env.storage().instance().set(&DataKey::XlmToken, &xlm_token);
@@ -165,6 +407,11 @@ impl VantisPoolContract {
         env.storage().instance().set(&DataKey::PoolReserves, &0i128);
         env.storage().instance().set(&DataKey::ProtocolFees, &0i128);
         env.storage().instance().set(&DataKey::CollateralAssets, &Vec::<Address>::new(&env));
+        if let Some(tag) = instance_tag {
+            env.storage().instance().set(&DataKey::InstanceTag, &tag);
+        }
+
+        Ok(())
     }
 
     /// Add a supported collateral asset
@@ -176,6 +423,27 @@ impl VantisPoolContract {
         caller.require_auth();
         Self::require_admin(&env, &caller)?;
 
+        // Require 0 < collateral_factor <= liquidation_threshold - buffer, threshold <= 100%
+        let buffer = Self::get_liquidation_buffer(env.clone());
+        if config.collateral_factor == 0
+            || config.liquidation_threshold == 0
+            || config.collateral_factor + buffer > config.liquidation_threshold
+            || config.liquidation_threshold > 10000
+            || config.decimals > ACCOUNTING_DECIMALS
+        {
+            return Err(PoolError::InvalidParams);
+        }
+
+        // A ramp may only raise the factor, within the liquidation threshold
+        if let CollateralRamp::Linear(ramp) = &config.ramp {
+            if ramp.start_factor == 0
+                || ramp.start_factor > ramp.end_factor
+                || ramp.end_factor + buffer > config.liquidation_threshold
+            {
+                return Err(PoolError::InvalidParams);
+            }
+        }
+
         let mut assets: Vec<Address> = env
             .storage()
             .instance()
@@ -190,7 +458,8 @@ impl VantisPoolContract {
             .instance()
             .set(&DataKey::TotalDeposits(config.token.clone()), &0i128);
 
-        env.events().publish(
+        Self::publish_event(
+            &env,
             (symbol_short!("asset"), symbol_short!("added")),
             config.token,
         );
@@ -198,9 +467,91 @@ impl VantisPoolContract {
         Ok(())
     }
 
+    /// Rewrite persisted collateral configs in the current schema
+    ///
+    /// Configs stored before a field was added to `CollateralConfig` no
+    /// longer deserialize. This reads each listed asset's raw entry and
+    /// upgrades legacy layouts, filling new fields with defaults.
+    /// Configs already in the current layout are left untouched.
+    ///
+    /// Returns the number of configs migrated.
+    pub fn migrate_collateral_configs(env: Env, caller: Address) -> Result<u32, PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        let assets: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CollateralAssets)
+            .unwrap_or(Vec::new(&env));
+
+        let mut migrated: u32 = 0;
+
+        for asset in assets.iter() {
+            // Structs are stored as symbol-keyed maps; inspect the fields
+            // present rather than decoding into a layout that may not match
+            let raw: Map<Symbol, Val> = match env.storage().persistent().get(&asset) {
+                Some(raw) => raw,
+                None => continue,
+            };
+
+            if raw.contains_key(Symbol::new(&env, "ramp")) {
+                continue;
+            }
+
+            let config = if raw.contains_key(Symbol::new(&env, "decimals")) {
+                let pre_ramp: PreRampCollateralConfig = env
+                    .storage()
+                    .persistent()
+                    .get(&asset)
+                    .ok_or(PoolError::InvalidParams)?;
+
+                CollateralConfig {
+                    token: pre_ramp.token,
+                    symbol: pre_ramp.symbol,
+                    collateral_factor: pre_ramp.collateral_factor,
+                    liquidation_threshold: pre_ramp.liquidation_threshold,
+                    liquidation_penalty: pre_ramp.liquidation_penalty,
+                    is_active: pre_ramp.is_active,
+                    decimals: pre_ramp.decimals,
+                    ramp: CollateralRamp::Disabled,
+                }
+            } else {
+                let legacy: LegacyCollateralConfig = env
+                    .storage()
+                    .persistent()
+                    .get(&asset)
+                    .ok_or(PoolError::InvalidParams)?;
+
+                CollateralConfig {
+                    token: legacy.token,
+                    symbol: legacy.symbol,
+                    collateral_factor: legacy.collateral_factor,
+                    liquidation_threshold: legacy.liquidation_threshold,
+                    liquidation_penalty: legacy.liquidation_penalty,
+                    is_active: legacy.is_active,
+                    decimals: DEFAULT_COLLATERAL_DECIMALS,
+                    ramp: CollateralRamp::Disabled,
+                }
+            };
+            env.storage().persistent().set(&asset, &config);
+            migrated += 1;
+        }
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("migrated")),
+            migrated,
+        );
+
+        Ok(migrated)
+    }
+
     // ============ Collateral Functions ============
 
     /// Deposit collateral into the pool via Blend adapter
+    ///
+    /// Fails with `InvalidAmount` if the user holds less than `amount`.
     pub fn deposit(
         env: Env,
         user: Address,
@@ -209,378 +560,422 @@ impl VantisPoolContract {
     ) -> Result<(), PoolError> {
         user.require_auth();
 
-        if amount <= 0 {
-            return Err(PoolError::InvalidAmount);
-        }
-
-        Self::require_asset_supported(&env, &asset)?;
-
-        // Get Blend adapter address
-        let blend_pool: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::BlendPool)
-            .ok_or(PoolError::BlendAdapterError)?;
+        Self::apply_deposit(env, user, asset, amount)
+    }
 
-        // Transfer tokens from user to this contract first
-        let token_client = token::Client::new(&env, &asset);
-        token_client.transfer(&user, &env.current_contract_address(), &amount);
+    /// Withdraw collateral from the pool via Blend adapter
+    pub fn withdraw(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), PoolError> {
+        user.require_auth();
 
-        // Approve Blend adapter to spend the tokens
-        // Set expiration to current ledger + 1000 ledgers (about 1.4 hours)
-        let expiration_ledger = env.ledger().sequence() + 1000;
-        token_client.approve(&env.current_contract_address(), &blend_pool, &amount, &expiration_ledger);
+        Self::apply_withdraw(env, user, asset, amount)
+    }
 
-        // Route through Blend adapter by invoking its deposit_collateral function
-        // Note: In production, this would use the blend-adapter contract client
-        // For now, we track the deposit locally and emit an event
-        env.events().publish(
-            (symbol_short!("blend"), symbol_short!("deposit")),
-            (&user, &asset, amount),
-        );
+    // ============ Borrow Functions ============
 
-        // Update user's collateral position locally for tracking
-        let mut user_collateral: Map<Address, i128> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Collateral(user.clone()))
-            .unwrap_or(Map::new(&env));
+    /// Borrow USDC against deposited collateral via Blend adapter
+    ///
+    /// If an origination fee is configured it is credited to protocol fees.
+    /// In `Deduct` mode the user receives `amount` minus the fee; in
+    /// `AddToDebt` mode the user receives `amount` and owes `amount` plus
+    /// the fee.
+    ///
+    /// Fails with `BorrowAssetNotAllowed` if any collateral the user holds is
+    /// restricted to other borrow assets.
+    pub fn borrow(env: Env, user: Address, amount: i128) -> Result<(), PoolError> {
+        user.require_auth();
 
-        let current = user_collateral.get(asset.clone()).unwrap_or(0);
-        user_collateral.set(asset.clone(), current + amount);
+        Self::apply_borrow(env, user, amount)
+    }
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Collateral(user.clone()), &user_collateral);
+    /// Deposit collateral and borrow against it in one transaction
+    ///
+    /// Mirrors the adapter's multi-operation `submit`: the deposit is applied
+    /// first, then the borrow, whose collateral check is the single health
+    /// check on the combined position. If the borrow fails the deposit is
+    /// reverted along with it.
+    pub fn deposit_and_borrow(
+        env: Env,
+        user: Address,
+        collateral_asset: Address,
+        deposit_amount: i128,
+        borrow_amount: i128,
+    ) -> Result<(), PoolError> {
+        user.require_auth();
 
-        // Update total deposits
-        let total: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalDeposits(asset.clone()))
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalDeposits(asset.clone()), &(total + amount));
+        Self::apply_deposit(env.clone(), user.clone(), collateral_asset, deposit_amount)?;
+        Self::apply_borrow(env, user, borrow_amount)
+    }
 
-        env.events().publish(
-            (symbol_short!("deposit"), user.clone()),
-            (&asset, amount),
-        );
+    /// Repay borrowed USDC via Blend adapter
+    pub fn repay(env: Env, user: Address, amount: i128) -> Result<(), PoolError> {
+        user.require_auth();
 
-        Ok(())
+        Self::apply_repay(env, user, amount)
     }
 
-    /// Withdraw collateral from the pool via Blend adapter
-    pub fn withdraw(
+    /// Repay debt and withdraw collateral in one transaction
+    ///
+    /// The repayment is applied first, so the withdrawal's health checks run
+    /// once on the position after the debt is reduced. If the withdrawal
+    /// fails the repayment is reverted along with it.
+    pub fn repay_and_withdraw(
         env: Env,
         user: Address,
-        asset: Address,
-        amount: i128,
+        repay_amount: i128,
+        collateral_asset: Address,
+        withdraw_amount: i128,
     ) -> Result<(), PoolError> {
         user.require_auth();
 
-        if amount <= 0 {
-            return Err(PoolError::InvalidAmount);
-        }
+        Self::apply_repay(env.clone(), user.clone(), repay_amount)?;
+        Self::apply_withdraw(env, user, collateral_asset, withdraw_amount)
+    }
 
-        // Get user's collateral
-        let mut user_collateral: Map<Address, i128> = env
+    /// Accrue a user's pending interest in chunks (callable by anyone)
+    ///
+    /// Keeps dormant positions current without the borrower acting: emits
+    /// the usual accrual event and republishes the pool utilization.
+    /// Advances at most `max_accrual_period` seconds per call, so keepers
+    /// can bring a long-untouched position up to date gradually instead of
+    /// in one large jump. Borrows, repays and closes still settle the full
+    /// backlog first, since they change the principal it accrues on.
+    ///
+    /// # Returns
+    /// Seconds still left to accrue
+    pub fn poke(env: Env, user: Address) -> Result<u64, PoolError> {
+        let max_period = match Self::get_max_accrual_period(env.clone()) {
+            0 => u64::MAX,
+            period => period,
+        };
+
+        let remaining = Self::accrue_interest_up_to(&env, &user, max_period)?;
+        Self::emit_utilization_changed(&env);
+
+        Ok(remaining)
+    }
+
+    /// Close a position: repay all debt and withdraw all collateral
+    ///
+    /// Accrues interest, pulls the exact outstanding debt from the user,
+    /// then returns every collateral balance. Fails with
+    /// `InsufficientBalance` before moving any funds if the user cannot
+    /// cover the debt.
+    ///
+    /// # Returns
+    /// Debt repaid (principal plus accrued interest)
+    pub fn close_position(env: Env, user: Address) -> Result<i128, PoolError> {
+        user.require_auth();
+
+        // Accrue interest first
+        Self::accrue_interest(&env, &user)?;
+
+        let borrow_data: BorrowData = env
             .storage()
             .persistent()
-            .get(&DataKey::Collateral(user.clone()))
-            .ok_or(PoolError::InsufficientCollateral)?;
+            .get(&DataKey::Borrow(user.clone()))
+            .unwrap_or_default();
+        let total_debt = borrow_data.principal + borrow_data.accrued_interest;
 
-        let current = user_collateral.get(asset.clone()).unwrap_or(0);
-        if current < amount {
-            return Err(PoolError::InsufficientCollateral);
+        // Round up so interest below token precision is still covered
+        let mut debt_tokens = Self::borrow_from_accounting(&env, total_debt);
+        if Self::borrow_to_accounting(&env, debt_tokens) < total_debt {
+            debt_tokens += 1;
         }
 
-        // Check if withdrawal would make position unhealthy
-        let new_amount = current - amount;
-        user_collateral.set(asset.clone(), new_amount);
+        if total_debt > 0 {
+            let borrow_token: Address = env.storage().instance().get(&DataKey::XlmToken).unwrap();
+            let token_client = token::Client::new(&env, &borrow_token);
 
-        // Temporarily update to check health factor
-        env.storage()
-            .persistent()
-            .set(&DataKey::Collateral(user.clone()), &user_collateral);
+            if token_client.balance(&user) < debt_tokens {
+                return Err(PoolError::InsufficientBalance);
+            }
 
-        let health_factor = Self::calculate_health_factor(&env, &user)?;
-        if health_factor < 10000 {
-            // HF < 1.0
-            // Revert the change
-            user_collateral.set(asset.clone(), current);
+            token_client.transfer(&user, &env.current_contract_address(), &debt_tokens);
+
+            Self::publish_event(
+                &env,
+                (symbol_short!("blend"), symbol_short!("repay")),
+                (&user, debt_tokens),
+            );
+
+            env.storage().persistent().set(
+                &DataKey::Borrow(user.clone()),
+                &BorrowData {
+                    principal: 0,
+                    accrued_interest: 0,
+                    last_accrual: env.ledger().timestamp(),
+                },
+            );
+
+            // Update pool state
+            Self::credit_repayment(&env, total_debt, borrow_data.accrued_interest);
+
+            // Interest is never added to total borrows, so only principal leaves it
+            let total_borrows: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalBorrows)
+                .unwrap_or(0);
             env.storage()
-                .persistent()
-                .set(&DataKey::Collateral(user.clone()), &user_collateral);
-            return Err(PoolError::WithdrawalWouldLiquidate);
+                .instance()
+                .set(&DataKey::TotalBorrows, &(total_borrows - borrow_data.principal));
+
+            Self::publish_event(
+                &env,
+                (symbol_short!("repay"), user.clone()),
+                debt_tokens,
+            );
+            Self::emit_utilization_changed(&env);
         }
 
-        // Get Blend adapter address
-        let _blend_pool: Address = env
+        // With no debt left every collateral balance can be released
+        let user_collateral: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey::BlendPool)
-            .ok_or(PoolError::BlendAdapterError)?;
+            .persistent()
+            .get(&DataKey::Collateral(user.clone()))
+            .unwrap_or(Map::new(&env));
 
-        // Route through Blend adapter by invoking its withdraw_collateral function
-        // Note: In production, this would use the blend-adapter contract client
-        // For now, we track the withdrawal locally and emit an event
-        env.events().publish(
-            (symbol_short!("blend"), symbol_short!("withdraw")),
-            (&user, &asset, amount),
-        );
+        for (asset, amount) in user_collateral.iter() {
+            if amount <= 0 {
+                continue;
+            }
+
+            let token_client = token::Client::new(&env, &asset);
+
+            // A delisted asset has lost its config but must still be released
+            let decimals = match Self::collateral_decimals(&env, &asset) {
+                Ok(decimals) => decimals,
+                Err(_) => token_client.decimals(),
+            };
+            let token_amount = Self::from_accounting(amount, decimals);
+
+            Self::publish_event(
+                &env,
+                (symbol_short!("blend"), symbol_short!("withdraw")),
+                (&user, &asset, token_amount),
+            );
+
+            token_client.transfer(&env.current_contract_address(), &user, &token_amount);
+
+            let total: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalDeposits(asset.clone()))
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalDeposits(asset.clone()), &(total - amount));
+
+            Self::publish_event(
+                &env,
+                (symbol_short!("withdraw"), user.clone()),
+                (&asset, token_amount),
+            );
+        }
 
-        // Update total deposits
-        let total: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::TotalDeposits(asset.clone()))
-            .unwrap_or(0);
         env.storage()
-            .instance()
-            .set(&DataKey::TotalDeposits(asset.clone()), &(total - amount));
+            .persistent()
+            .remove(&DataKey::Collateral(user.clone()));
 
-        env.events().publish(
-            (symbol_short!("withdraw"), user.clone()),
-            (&asset, amount),
+        Self::publish_event(
+            &env,
+            (symbol_short!("position"), symbol_short!("closed")),
+            (&user, debt_tokens),
         );
 
-        Ok(())
+        Ok(debt_tokens)
     }
 
-    // ============ Borrow Functions ============
-
-    /// Borrow USDC against deposited collateral via Blend adapter
-    pub fn borrow(env: Env, user: Address, amount: i128) -> Result<(), PoolError> {
-        user.require_auth();
+    /// Supply liquidity to the pool (for lenders)
+    ///
+    /// The pool token is both supplied and lent, so reserves are credited
+    /// with the supplied token amount. Reserves, borrows and repayments all
+    /// stay in pool-token units; USD values are only derived in views.
+    pub fn supply(env: Env, supplier: Address, amount: i128) -> Result<(), PoolError> {
+        supplier.require_auth();
 
         if amount <= 0 {
             return Err(PoolError::InvalidAmount);
         }
 
-        // Accrue interest first
-        Self::accrue_interest(&env, &user)?;
-
-        // Check pool liquidity
-        let reserves: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::PoolReserves)
-            .unwrap_or(0);
-
-        if reserves < amount {
-            return Err(PoolError::InsufficientLiquidity);
-        }
-
-        // Get user's borrowing capacity
-        let borrow_capacity = Self::get_borrow_capacity(&env, &user)?;
-
-        // Get current borrow
-        let mut borrow_data: BorrowData = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Borrow(user.clone()))
-            .unwrap_or(BorrowData {
-                principal: 0,
-                accrued_interest: 0,
-                last_accrual: env.ledger().timestamp(),
-            });
+        let internal_amount = Self::borrow_to_accounting(&env, amount);
 
-        let total_debt = borrow_data.principal + borrow_data.accrued_interest;
-        if total_debt + amount > borrow_capacity {
-            return Err(PoolError::InsufficientCollateral);
+        // Transfer the pool token from supplier to pool
+        let xlm: Address = env.storage().instance().get(&DataKey::XlmToken).unwrap();
+        let token_client = token::Client::new(&env, &xlm);
+        if token_client.balance(&supplier) < amount {
+            return Err(PoolError::InvalidAmount);
         }
+        token_client.transfer(&supplier, &env.current_contract_address(), &amount);
 
-        // Get Blend adapter address
-        let _blend_pool: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::BlendPool)
-            .ok_or(PoolError::BlendAdapterError)?;
-
-        // Route through Blend adapter by invoking its borrow function
-        // Note: In production, this would use the blend-adapter contract client
-        // For now, we track the borrow locally and emit an event
-        env.events().publish(
-            (symbol_short!("blend"), symbol_short!("borrow")),
-            (&user, amount),
-        );
-
-        // Update borrow position
-        borrow_data.principal += amount;
-        borrow_data.last_accrual = env.ledger().timestamp();
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Borrow(user.clone()), &borrow_data);
-
-        // Update pool state
-        env.storage()
-            .instance()
-            .set(&DataKey::PoolReserves, &(reserves - amount));
-
-        let total_borrows: i128 = env
+        // Update pool reserves
+        let reserves: i128 = env
             .storage()
             .instance()
-            .get(&DataKey::TotalBorrows)
+            .get(&DataKey::PoolReserves)
             .unwrap_or(0);
         env.storage()
             .instance()
-            .set(&DataKey::TotalBorrows, &(total_borrows + amount));
+            .set(&DataKey::PoolReserves, &(reserves + internal_amount));
 
-        env.events().publish(
-            (symbol_short!("borrow"), user.clone()),
+        Self::publish_event(
+            &env,
+            (symbol_short!("supply"), supplier.clone()),
             amount,
         );
 
         Ok(())
     }
 
-    /// Repay borrowed USDC via Blend adapter
-    pub fn repay(env: Env, user: Address, amount: i128) -> Result<(), PoolError> {
-        user.require_auth();
-
-        if amount <= 0 {
-            return Err(PoolError::InvalidAmount);
-        }
-
-        // Accrue interest first
-        Self::accrue_interest(&env, &user)?;
-
-        let mut borrow_data: BorrowData = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Borrow(user.clone()))
-            .ok_or(PoolError::NoBorrowPosition)?;
-
-        let total_debt = borrow_data.principal + borrow_data.accrued_interest;
-        if total_debt == 0 {
-            return Err(PoolError::NoBorrowPosition);
-        }
-
-        let repay_amount = if amount > total_debt { total_debt } else { amount };
-
-        // Get Blend adapter address
-        let _blend_pool: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::BlendPool)
-            .ok_or(PoolError::BlendAdapterError)?;
-
-        // Route through Blend adapter by invoking its repay function
-        // Note: In production, this would use the blend-adapter contract client
-        // For now, we track the repay locally and emit an event
-        env.events().publish(
-            (symbol_short!("blend"), symbol_short!("repay")),
-            (&user, repay_amount),
-        );
-
-        // Apply repayment: first to interest, then to principal
-        if repay_amount <= borrow_data.accrued_interest {
-            borrow_data.accrued_interest -= repay_amount;
-        } else {
-            let remaining = repay_amount - borrow_data.accrued_interest;
-            borrow_data.accrued_interest = 0;
-            borrow_data.principal -= remaining;
-        }
-
-        borrow_data.last_accrual = env.ledger().timestamp();
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Borrow(user.clone()), &borrow_data);
+    // ============ Health & Risk Functions ============
 
-        // Update pool state
-        let reserves: i128 = env
+    /// Get a dashboard summary of a user's position
+    ///
+    /// Unlike `get_health_factor`, a stale oracle price does not fail the
+    /// call: the last known price is used and `stale` is set. Borrows and
+    /// withdrawals still reject stale prices.
+    pub fn get_account_summary(env: Env, user: Address) -> Result<AccountSummary, PoolError> {
+        let oracle_address: Address = env
             .storage()
             .instance()
-            .get(&DataKey::PoolReserves)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::PoolReserves, &(reserves + repay_amount));
+            .get(&DataKey::Oracle)
+            .ok_or(PoolError::OracleError)?;
 
-        let total_borrows: i128 = env
+        let user_collateral: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey::TotalBorrows)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::TotalBorrows, &(total_borrows - repay_amount));
+            .persistent()
+            .get(&DataKey::Collateral(user.clone()))
+            .unwrap_or(Map::new(&env));
 
-        env.events().publish(
-            (symbol_short!("repay"), user.clone()),
-            repay_amount,
-        );
+        let mut collateral_value: i128 = 0;
+        let mut weighted_value: i128 = 0;
+        let mut capacity: i128 = 0;
+        let mut stale = false;
 
-        Ok(())
-    }
+        for (asset, amount) in user_collateral.iter() {
+            // Delisted assets are valued at zero, as elsewhere
+            let config: CollateralConfig = match env.storage().persistent().get(&asset) {
+                Some(config) => config,
+                None => continue,
+            };
+            if amount == 0 {
+                continue;
+            }
 
-    /// Supply XLM liquidity to the pool (for lenders)
-    pub fn supply(env: Env, supplier: Address, amount: i128) -> Result<(), PoolError> {
-        supplier.require_auth();
+            let (price, price_stale) =
+                oracle::get_view_price(&env, &oracle_address, &config.symbol)?;
+            stale |= price_stale;
 
-        if amount <= 0 {
-            return Err(PoolError::InvalidAmount);
+            let value = oracle::calculate_value(amount, price);
+            let collateral_factor = config.effective_collateral_factor(env.ledger().timestamp());
+            collateral_value += value;
+            weighted_value += value * config.liquidation_threshold as i128 / 10000;
+            capacity += value * collateral_factor as i128 / 10000;
         }
 
-        // Transfer XLM from supplier to pool
-        let xlm: Address = env.storage().instance().get(&DataKey::XlmToken).unwrap();
-        let token_client = token::Client::new(&env, &xlm);
-        token_client.transfer(&supplier, &env.current_contract_address(), &amount);
-
-        // Update pool reserves
-        let reserves: i128 = env
+        let borrow_data: BorrowData = env
             .storage()
-            .instance()
-            .get(&DataKey::PoolReserves)
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&DataKey::PoolReserves, &(reserves + amount));
+            .persistent()
+            .get(&DataKey::Borrow(user))
+            .unwrap_or_default();
+        let debt = borrow_data.principal + borrow_data.accrued_interest;
 
-        env.events().publish(
-            (symbol_short!("supply"), supplier.clone()),
-            amount,
-        );
+        let health_factor = if debt == 0 {
+            i128::MAX
+        } else {
+            weighted_value * Self::hf_scale(&env) / debt
+        };
 
-        Ok(())
+        Ok(AccountSummary {
+            collateral_value: Self::borrow_from_accounting(&env, collateral_value),
+            debt: Self::borrow_from_accounting(&env, debt),
+            health_factor,
+            borrow_capacity: Self::borrow_from_accounting(&env, (capacity - debt).max(0)),
+            stale,
+        })
     }
 
-    // ============ Health & Risk Functions ============
-
-    /// Get health factor for a user (in basis points, 10000 = 1.0)
+    /// Get health factor for a user (at `get_hf_scale`, 10000 = 1.0 by default)
     pub fn get_health_factor(env: Env, user: Address) -> Result<i128, PoolError> {
         Self::calculate_health_factor(&env, &user)
     }
 
-    /// Get user's borrowing capacity in USDC (internal)
-    fn get_borrow_capacity(env: &Env, user: &Address) -> Result<i128, PoolError> {
-        let user_collateral: Map<Address, i128> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Collateral(user.clone()))
-            .unwrap_or(Map::new(env));
+    /// Check whether a user's position can be liquidated
+    ///
+    /// Uses the same `HealthStatus` classification as the health module so
+    /// integrators do not re-implement the threshold comparison.
+    pub fn is_liquidatable(env: Env, user: Address) -> Result<bool, PoolError> {
+        let health_factor = Self::calculate_health_factor(&env, &user)?;
+        let status = HealthStatus::from_scaled_health_factor(health_factor, Self::hf_scale(&env));
+        Ok(status == HealthStatus::Liquidatable)
+    }
 
-        let mut total_capacity: i128 = 0;
+    /// Check whether a user's position is classified as healthy
+    pub fn is_healthy(env: Env, user: Address) -> Result<bool, PoolError> {
+        let health_factor = Self::calculate_health_factor(&env, &user)?;
+        let status = HealthStatus::from_scaled_health_factor(health_factor, Self::hf_scale(&env));
+        Ok(status == HealthStatus::Healthy)
+    }
 
-        for (asset, amount) in user_collateral.iter() {
-            let config: CollateralConfig = env
-                .storage()
-                .persistent()
-                .get(&asset)
-                .ok_or(PoolError::AssetNotSupported)?;
+    /// Get how much more a user can borrow (borrow-token units)
+    ///
+    /// Collateral-factor-weighted collateral value minus current debt, floored
+    /// at zero. This is the authoritative figure; the risk engine defers to it.
+    pub fn get_borrow_capacity(env: Env, user: Address) -> Result<i128, PoolError> {
+        let capacity = Self::calculate_borrow_capacity(&env, &user, 0)?;
+        Ok(Self::borrow_from_accounting(&env, capacity))
+    }
 
-            // Get asset price from oracle (simplified: would need oracle integration)
-            // For now, assume 1:1 with USDC for simplicity
-            let asset_value = amount; // In production: amount * price / decimals
+    /// Get how much a user can borrow while staying clear of liquidation
+    ///
+    /// Same as `get_borrow_capacity` but with every collateral factor lowered
+    /// by the liquidation buffer, so a position borrowed up to this limit
+    /// keeps at least twice the buffer between it and its liquidation threshold.
+    pub fn get_safe_borrow_limit(env: Env, user: Address) -> Result<i128, PoolError> {
+        let buffer = Self::get_liquidation_buffer(env.clone());
+        let capacity = Self::calculate_borrow_capacity(&env, &user, buffer)?;
+        Ok(Self::borrow_from_accounting(&env, capacity))
+    }
 
-            let collateral_value = asset_value * config.collateral_factor as i128 / 10000;
-            total_capacity += collateral_value;
+    /// Get per-asset collateral breakdown for a user
+    ///
+    /// Returns `(asset, amount, usd_value, weighted_value)` for each deposited
+    /// asset. Weighted values sum to the collateral used in `get_health_factor`.
+    /// Amounts are in token units; values are in borrow-token units.
+    pub fn get_collateral_breakdown(
+        env: Env,
+        user: Address,
+    ) -> Result<Vec<(Address, i128, i128, i128)>, PoolError> {
+        let mut breakdown = Vec::new(&env);
+
+        for (asset, amount, value, weighted) in Self::calculate_collateral_breakdown(&env, &user)?.iter() {
+            let decimals = Self::collateral_decimals(&env, &asset).unwrap_or(ACCOUNTING_DECIMALS);
+            breakdown.push_back((
+                asset,
+                Self::from_accounting(amount, decimals),
+                Self::borrow_from_accounting(&env, value),
+                Self::borrow_from_accounting(&env, weighted),
+            ));
         }
 
+        Ok(breakdown)
+    }
+
+    /// Get user's borrowing capacity in USDC (internal)
+    ///
+    /// `buffer` is subtracted from each asset's collateral factor (basis points).
+    fn calculate_borrow_capacity(
+        env: &Env,
+        user: &Address,
+        buffer: u32,
+    ) -> Result<i128, PoolError> {
+        let total_capacity = Self::calculate_weighted_collateral(env, user, buffer)?;
+
         // Subtract current debt
         let borrow_data: BorrowData = env
             .storage()
@@ -594,31 +989,42 @@ impl VantisPoolContract {
         Ok(if available > 0 { available } else { 0 })
     }
 
-    /// Calculate health factor internally
-    fn calculate_health_factor(env: &Env, user: &Address) -> Result<i128, PoolError> {
+    /// Collateral value weighted by collateral factor less `buffer`
+    fn calculate_weighted_collateral(
+        env: &Env,
+        user: &Address,
+        buffer: u32,
+    ) -> Result<i128, PoolError> {
         let user_collateral: Map<Address, i128> = env
             .storage()
             .persistent()
             .get(&DataKey::Collateral(user.clone()))
             .unwrap_or(Map::new(env));
 
-        let mut total_collateral_value: i128 = 0;
+        let mut total_capacity: i128 = 0;
 
         for (asset, amount) in user_collateral.iter() {
-            let config: CollateralConfig = env
-                .storage()
-                .persistent()
-                .get(&asset)
-                .ok_or(PoolError::AssetNotSupported)?;
-
-            // Get asset price from oracle (simplified)
-            let asset_value = amount; // In production: amount * price / decimals
-
-            let liquidation_value =
-                asset_value * config.liquidation_threshold as i128 / 10000;
-            total_collateral_value += liquidation_value;
+            // A delisted asset cannot be priced: it backs nothing but must not
+            // lock the user out of repaying or withdrawing other assets
+            let config: CollateralConfig = match env.storage().persistent().get(&asset) {
+                Some(config) => config,
+                None => continue,
+            };
+
+            let asset_value = Self::get_asset_value(env, &config, amount)?;
+
+            let collateral_factor = config
+                .effective_collateral_factor(env.ledger().timestamp())
+                .saturating_sub(buffer);
+            let collateral_value = asset_value * collateral_factor as i128 / 10000;
+            total_capacity += collateral_value;
         }
 
+        Ok(total_capacity)
+    }
+
+    /// Calculate health factor internally
+    fn calculate_health_factor(env: &Env, user: &Address) -> Result<i128, PoolError> {
         let borrow_data: BorrowData = env
             .storage()
             .persistent()
@@ -631,88 +1037,390 @@ impl VantisPoolContract {
             return Ok(i128::MAX); // No debt = infinite health
         }
 
-        // Health factor = total_collateral_value / total_debt * 10000
-        let health_factor = total_collateral_value * 10000 / total_debt;
+        let mut total_collateral_value: i128 = 0;
+        for (_, _, _, weighted_value) in Self::calculate_collateral_breakdown(env, user)?.iter() {
+            total_collateral_value += weighted_value;
+        }
+
+        // Health factor = total_collateral_value / total_debt * scale
+        let health_factor = total_collateral_value * Self::hf_scale(env) / total_debt;
 
         Ok(health_factor)
     }
 
-    /// Accrue interest on a user's borrow position
-    fn accrue_interest(env: &Env, user: &Address) -> Result<(), PoolError> {
-        let mut borrow_data: BorrowData = env
+    /// Per-asset (asset, amount, USD value, weighted value) for a user's collateral
+    ///
+    /// Weighted value applies the asset's liquidation threshold, matching the
+    /// collateral figure used by the health factor.
+    fn calculate_collateral_breakdown(
+        env: &Env,
+        user: &Address,
+    ) -> Result<Vec<(Address, i128, i128, i128)>, PoolError> {
+        let user_collateral: Map<Address, i128> = env
             .storage()
             .persistent()
-            .get(&DataKey::Borrow(user.clone()))
-            .unwrap_or_default();
+            .get(&DataKey::Collateral(user.clone()))
+            .unwrap_or(Map::new(env));
 
-        if borrow_data.principal == 0 {
-            return Ok(());
+        let mut breakdown = Vec::new(env);
+
+        for (asset, amount) in user_collateral.iter() {
+            // Delisted assets are reported at zero value, as in borrow capacity
+            let config: CollateralConfig = match env.storage().persistent().get(&asset) {
+                Some(config) => config,
+                None => {
+                    breakdown.push_back((asset, amount, 0, 0));
+                    continue;
+                }
+            };
+
+            let asset_value = Self::get_asset_value(env, &config, amount)?;
+            let weighted_value = asset_value * config.liquidation_threshold as i128 / 10000;
+
+            breakdown.push_back((asset, amount, asset_value, weighted_value));
         }
 
-        let current_time = env.ledger().timestamp();
-        let time_elapsed = current_time - borrow_data.last_accrual;
+        Ok(breakdown)
+    }
 
-        if time_elapsed == 0 {
-            return Ok(());
+    /// Value an accounting-unit collateral amount in USD using the oracle price
+    ///
+    /// Errors with `OracleError` if the price is missing or zero rather
+    /// than valuing the collateral at zero.
+    fn get_asset_value(
+        env: &Env,
+        config: &CollateralConfig,
+        amount: i128,
+    ) -> Result<i128, PoolError> {
+        if amount == 0 {
+            return Ok(0);
         }
 
-        // Get interest rate
-        let interest_rate = Self::get_current_interest_rate(env)?;
+        let oracle_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Oracle)
+            .ok_or(PoolError::OracleError)?;
 
-        // Calculate interest: principal * rate * time / (365 days * 10000 basis points)
-        let seconds_per_year: u64 = 365 * 24 * 60 * 60;
-        let interest = borrow_data.principal * interest_rate as i128 * time_elapsed as i128
-            / (seconds_per_year as i128 * 10000);
+        let price = oracle::get_asset_price(env, &oracle_address, &config.symbol)?;
 
-        borrow_data.accrued_interest += interest;
-        borrow_data.last_accrual = current_time;
+        Ok(oracle::calculate_value(amount, price))
+    }
 
+    /// Health-factor scale (10000 = basis points unless configured)
+    fn hf_scale(env: &Env) -> i128 {
         env.storage()
-            .persistent()
-            .set(&DataKey::Borrow(user.clone()), &borrow_data);
-
-        Ok(())
+            .instance()
+            .get(&DataKey::HfScale)
+            .unwrap_or(DEFAULT_HF_SCALE)
     }
 
-    /// Get current interest rate based on utilization
-    fn get_current_interest_rate(env: &Env) -> Result<u32, PoolError> {
-        let params: InterestRateParams = env
-            .storage()
-            .instance()
-            .get(&DataKey::InterestParams)
-            .unwrap();
+    /// Publish an event, prefixed with the deployment's instance tag if set
+    ///
+    /// The payload is `(seq, data)`, where `seq` increases by one with every
+    /// event so indexers can order events emitted in the same ledger.
+    fn publish_event<T, D>(env: &Env, topics: T, data: D)
+    where
+        T: IntoVal<Env, Vec<Val>>,
+        D: IntoVal<Env, Val>,
+    {
+        let mut topics: Vec<Val> = topics.into_val(env);
+        if let Some(tag) = env.storage().instance().get::<_, Symbol>(&DataKey::InstanceTag) {
+            topics.push_front(tag.into_val(env));
+        }
 
-        let reserves: i128 = env
-            .storage()
-            .instance()
-            .get(&DataKey::PoolReserves)
-            .unwrap_or(0);
+        let seq = Self::get_event_seq(env.clone()) + 1;
+        env.storage().instance().set(&DataKey::EventSeq, &seq);
 
-        let total_borrows: i128 = env
+        env.events().publish(topics, (seq, data.into_val(env)));
+    }
+
+    /// Reject borrows of an asset that some held collateral may not back
+    fn require_borrow_allowed(
+        env: &Env,
+        user: &Address,
+        borrow_asset: &Address,
+    ) -> Result<(), PoolError> {
+        let user_collateral: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey::TotalBorrows)
-            .unwrap_or(0);
+            .persistent()
+            .get(&DataKey::Collateral(user.clone()))
+            .unwrap_or(Map::new(env));
 
-        let total_liquidity = reserves + total_borrows;
-        if total_liquidity == 0 {
-            return Ok(params.base_rate);
+        for (collateral, amount) in user_collateral.iter() {
+            if amount <= 0 {
+                continue;
+            }
+            if let Some(allowed) = Self::get_allowed_borrow_assets(env.clone(), collateral) {
+                if !allowed.contains(borrow_asset) {
+                    return Err(PoolError::BorrowAssetNotAllowed);
+                }
+            }
         }
 
-        // Utilization = borrows / total_liquidity (in basis points)
-        let utilization = (total_borrows * 10000 / total_liquidity) as u32;
+        Ok(())
+    }
 
-        let rate = if utilization <= params.optimal_utilization {
-            // Below optimal: base_rate + (utilization * slope1 / optimal)
-            params.base_rate + utilization * params.slope1 / params.optimal_utilization
+    /// Reject borrows while the borrow asset trades outside its peg band
+    ///
+    /// Passes when no peg check is configured.
+    fn require_borrow_asset_pegged(env: &Env) -> Result<(), PoolError> {
+        let peg: PegCheck = match env.storage().instance().get(&DataKey::PegCheck) {
+            Some(peg) => peg,
+            None => return Ok(()),
+        };
+
+        let oracle_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Oracle)
+            .ok_or(PoolError::OracleError)?;
+
+        let price = oracle::get_asset_price(env, &oracle_address, &peg.symbol)?;
+        if !peg.is_pegged(price) {
+            return Err(PoolError::OracleError);
+        }
+
+        Ok(())
+    }
+
+    /// Oracle price of the borrow unit (14 decimals)
+    ///
+    /// Uses the peg-check symbol when configured, otherwise $1.
+    fn borrow_unit_price(env: &Env) -> Result<i128, PoolError> {
+        let peg: PegCheck = match env.storage().instance().get(&DataKey::PegCheck) {
+            Some(peg) => peg,
+            None => return Ok(oracle::PRICE_SCALAR),
+        };
+
+        let oracle_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Oracle)
+            .ok_or(PoolError::OracleError)?;
+
+        oracle::get_asset_price(env, &oracle_address, &peg.symbol)
+    }
+
+    /// Accrue all outstanding interest on a user's borrow position
+    fn accrue_interest(env: &Env, user: &Address) -> Result<(), PoolError> {
+        Self::accrue_interest_up_to(env, user, u64::MAX).map(|_| ())
+    }
+
+    /// Accrue interest for at most `max_period` seconds of the backlog
+    ///
+    /// Returns the seconds still left to accrue.
+    fn accrue_interest_up_to(
+        env: &Env,
+        user: &Address,
+        max_period: u64,
+    ) -> Result<u64, PoolError> {
+        let mut borrow_data: BorrowData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Borrow(user.clone()))
+            .unwrap_or_default();
+
+        if borrow_data.principal == 0 {
+            return Ok(0);
+        }
+
+        let time_elapsed = env.ledger().timestamp() - borrow_data.last_accrual;
+
+        if time_elapsed == 0 {
+            return Ok(0);
+        }
+
+        let period = time_elapsed.min(max_period);
+
+        // Get interest rate at full precision
+        let interest_rate = Self::current_precise_rate(env)?;
+        let rate_decimals = Self::get_rate_decimals(env.clone());
+
+        // Calculate interest: principal * rate * time / (365 days * 10000 basis points)
+        let interest = borrow::calculate_interest_precise(
+            borrow_data.principal,
+            interest_rate,
+            rate_decimals,
+            period,
+        );
+        let (interest, capped) = Self::cap_interest(env, &borrow_data, interest);
+
+        borrow_data.accrued_interest += interest;
+        borrow_data.last_accrual += period;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Borrow(user.clone()), &borrow_data);
+
+        if interest > 0 {
+            let total_debt = borrow_data.principal + borrow_data.accrued_interest;
+            Self::publish_event(
+                env,
+                (symbol_short!("interest"), symbol_short!("accrued")),
+                (
+                    user.clone(),
+                    Self::borrow_from_accounting(env, interest),
+                    Self::borrow_from_accounting(env, total_debt),
+                    (interest_rate / 10i128.pow(rate_decimals)) as u32,
+                ),
+            );
+        }
+
+        if capped {
+            Self::publish_event(
+                env,
+                (Symbol::new(env, "max_interest_reached"), user.clone()),
+                Self::borrow_from_accounting(env, borrow_data.accrued_interest),
+            );
+        }
+
+        Ok(time_elapsed - period)
+    }
+
+    /// Limit newly accrued interest to the position's remaining headroom
+    ///
+    /// Accrued interest may not exceed `get_max_interest_ratio` of the
+    /// principal, so an abandoned position stops growing once it gets there.
+    /// Returns the interest to book and whether the cap was reached.
+    fn cap_interest(env: &Env, borrow_data: &BorrowData, interest: i128) -> (i128, bool) {
+        let ratio = Self::get_max_interest_ratio(env.clone()) as i128;
+        let max_interest = borrow_data.principal.saturating_mul(ratio) / 10000;
+        let headroom = (max_interest - borrow_data.accrued_interest).max(0);
+
+        if interest >= headroom {
+            (headroom, headroom > 0)
+        } else {
+            (interest, false)
+        }
+    }
+
+    /// Return a repayment to reserves, keeping the reserve-factor share of
+    /// the interest it covers as protocol fees
+    ///
+    /// The fee is only taken once interest is actually paid, so an unpaid
+    /// position never costs suppliers anything.
+    fn credit_repayment(env: &Env, repaid: i128, interest_paid: i128) {
+        let fee = interest_paid * Self::get_reserve_factor(env.clone()) as i128 / 10000;
+
+        let reserves: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PoolReserves)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::PoolReserves, &(reserves + repaid - fee));
+
+        if fee > 0 {
+            let fees: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::ProtocolFees)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::ProtocolFees, &(fees + fee));
+        }
+    }
+
+    /// Current utilization (basis points, borrows / (reserves + borrows))
+    fn current_utilization(env: &Env) -> u32 {
+        let reserves: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PoolReserves)
+            .unwrap_or(0);
+
+        let total_borrows: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalBorrows)
+            .unwrap_or(0);
+
+        borrow::calculate_utilization(total_borrows, reserves + total_borrows)
+    }
+
+    /// Publish the post-action utilization after borrows and repays
+    fn emit_utilization_changed(env: &Env) {
+        Self::publish_event(
+            env,
+            (symbol_short!("util"), symbol_short!("changed")),
+            Self::current_utilization(env),
+        );
+    }
+
+    /// Get current interest rate based on utilization
+    fn get_current_interest_rate(env: &Env) -> Result<u32, PoolError> {
+        Self::interest_rate_at(env, Self::current_utilization(env))
+    }
+
+    /// Utilization, borrow rate and supply rate from a single utilization read
+    fn current_rates(env: &Env) -> Result<(u32, u32, u32), PoolError> {
+        let utilization = Self::current_utilization(env);
+        let borrow_rate = Self::interest_rate_at(env, utilization)?;
+        let supply_rate = borrow::calculate_supply_rate(
+            borrow_rate,
+            utilization,
+            Self::get_reserve_factor(env.clone()),
+        );
+
+        Ok((utilization, borrow_rate, supply_rate))
+    }
+
+    /// Borrow rate at a given utilization (basis points)
+    fn interest_rate_at(env: &Env, utilization: u32) -> Result<u32, PoolError> {
+        let scale = 10i128.pow(Self::get_rate_decimals(env.clone()));
+        Ok((Self::precise_rate_at(env, utilization)? / scale) as u32)
+    }
+
+    /// Current borrow rate at `get_rate_decimals` extra decimals
+    fn current_precise_rate(env: &Env) -> Result<i128, PoolError> {
+        Self::precise_rate_at(env, Self::current_utilization(env))
+    }
+
+    /// Borrow rate at a given utilization, at `get_rate_decimals` extra decimals
+    fn precise_rate_at(env: &Env, utilization: u32) -> Result<i128, PoolError> {
+        let params: InterestRateParams = env
+            .storage()
+            .instance()
+            .get(&DataKey::InterestParams)
+            .unwrap();
+        let max_rate = params.max_borrow_rate as i128;
+
+        // Delegate to the external model when one is configured; it reports basis points
+        if let Some(model) = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::InterestRateModel)
+        {
+            let rate = match rate_model::RateModelClient::new(env, &model).try_rate(&utilization) {
+                Ok(Ok(rate)) => rate as i128,
+                _ => return Err(PoolError::RateModelError),
+            };
+            let scale = 10i128.pow(Self::get_rate_decimals(env.clone()));
+            return Ok((rate * scale).min(max_rate));
+        }
+
+        let base_rate = params.base_rate as i128;
+        if utilization == 0 {
+            return Ok(base_rate.min(max_rate));
+        }
+
+        let utilization = utilization as i128;
+        let optimal = params.optimal_utilization as i128;
+        let rate = if utilization <= optimal {
+            // Below optimal: base_rate + (utilization * slope1 / optimal)
+            base_rate + utilization * params.slope1 as i128 / optimal
         } else {
             // Above optimal: base_rate + slope1 + ((utilization - optimal) * slope2 / (100% - optimal))
-            let excess = utilization - params.optimal_utilization;
-            let remaining = 10000 - params.optimal_utilization;
-            params.base_rate + params.slope1 + excess * params.slope2 / remaining
+            let excess = utilization - optimal;
+            let remaining = 10000 - optimal;
+            base_rate + params.slope1 as i128 + excess * params.slope2 as i128 / remaining
         };
 
-        Ok(rate)
+        // Never let a liquidity crunch push borrowers past the cap
+        Ok(rate.min(max_rate))
     }
 
     // ============ View Functions ============
@@ -725,36 +1433,202 @@ impl VantisPoolContract {
             .ok_or(PoolError::Unauthorized)
     }
 
-    /// Get user's collateral balances
+    /// Get the configuration of a collateral asset
+    pub fn get_collateral_config(env: Env, asset: Address) -> Option<CollateralConfig> {
+        env.storage().persistent().get(&asset)
+    }
+
+    /// Get the collateral factor currently applied to an asset (basis points)
+    ///
+    /// Follows the asset's ramp when one is configured.
+    pub fn get_effective_collateral_factor(env: Env, asset: Address) -> Result<u32, PoolError> {
+        let config: CollateralConfig = env
+            .storage()
+            .persistent()
+            .get(&asset)
+            .ok_or(PoolError::AssetNotSupported)?;
+
+        Ok(config.effective_collateral_factor(env.ledger().timestamp()))
+    }
+
+    /// Get user's collateral balances (token units)
     pub fn get_collateral(env: Env, user: Address) -> Map<Address, i128> {
-        env.storage()
+        let user_collateral: Map<Address, i128> = env
+            .storage()
             .persistent()
             .get(&DataKey::Collateral(user))
-            .unwrap_or(Map::new(&env))
+            .unwrap_or(Map::new(&env));
+
+        let mut balances = Map::new(&env);
+        for (asset, amount) in user_collateral.iter() {
+            let decimals = Self::collateral_decimals(&env, &asset).unwrap_or(ACCOUNTING_DECIMALS);
+            balances.set(asset, Self::from_accounting(amount, decimals));
+        }
+        balances
     }
 
-    /// Get user's borrow position
+    /// Get user's borrow position (borrow-token units)
     pub fn get_borrow(env: Env, user: Address) -> BorrowData {
-        env.storage()
+        let borrow_data: BorrowData = env
+            .storage()
             .persistent()
             .get(&DataKey::Borrow(user))
-            .unwrap_or_default()
+            .unwrap_or_default();
+
+        BorrowData {
+            principal: Self::borrow_from_accounting(&env, borrow_data.principal),
+            accrued_interest: Self::borrow_from_accounting(&env, borrow_data.accrued_interest),
+            last_accrual: borrow_data.last_accrual,
+        }
     }
 
-    /// Get pool reserves
-    pub fn get_reserves(env: Env) -> i128 {
+    /// Get every borrow position a user holds, keyed by borrow asset
+    ///
+    /// The pool lends a single borrow asset, so this holds at most one entry
+    /// and is empty without outstanding debt. Amounts match `get_borrow`.
+    pub fn get_all_borrows(env: Env, user: Address) -> Vec<(Address, BorrowData)> {
+        let mut borrows = Vec::new(&env);
+
+        let borrow_data = Self::get_borrow(env.clone(), user);
+        if borrow_data.principal > 0 || borrow_data.accrued_interest > 0 {
+            let borrow_token: Address = env.storage().instance().get(&DataKey::XlmToken).unwrap();
+            borrows.push_back((borrow_token, borrow_data));
+        }
+
+        borrows
+    }
+
+    /// Get a user's current total debt in USD (14 decimals)
+    ///
+    /// Includes interest accrued since the last interaction without
+    /// persisting it. The borrow unit is valued at $1, or at the oracle
+    /// price of the peg-check symbol when one is configured.
+    pub fn get_debt_usd(env: Env, user: Address) -> Result<i128, PoolError> {
+        let borrow_data: BorrowData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Borrow(user))
+            .unwrap_or_default();
+
+        if borrow_data.principal == 0 {
+            return Ok(0);
+        }
+
+        let elapsed = env.ledger().timestamp() - borrow_data.last_accrual;
+        let pending = borrow::calculate_interest_precise(
+            borrow_data.principal,
+            Self::current_precise_rate(&env)?,
+            Self::get_rate_decimals(env.clone()),
+            elapsed,
+        );
+        let (pending, _) = Self::cap_interest(&env, &borrow_data, pending);
+        let total_debt = borrow_data.principal + borrow_data.accrued_interest + pending;
+
+        Ok(oracle::calculate_value(total_debt, Self::borrow_unit_price(&env)?))
+    }
+
+    /// Get the debt-weighted average rate across a user's borrows
+    ///
+    /// Each borrow is weighted by its principal at the pool rate in effect
+    /// right after it was drawn. Repayments reduce every borrow pro rata, so
+    /// they leave the average unchanged.
+    ///
+    /// # Returns
+    /// Blended rate in basis points, or 0 without outstanding principal
+    pub fn get_blended_borrow_rate(env: Env, user: Address) -> u32 {
+        let borrow_data: BorrowData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Borrow(user.clone()))
+            .unwrap_or_default();
+
+        if borrow_data.principal <= 0 {
+            return 0;
+        }
+
         env.storage()
+            .persistent()
+            .get(&DataKey::BorrowRate(user))
+            .unwrap_or(0)
+    }
+
+    /// Get pool reserves (borrow-token units)
+    pub fn get_reserves(env: Env) -> i128 {
+        let reserves: i128 = env
+            .storage()
             .instance()
             .get(&DataKey::PoolReserves)
-            .unwrap_or(0)
+            .unwrap_or(0);
+        Self::borrow_from_accounting(&env, reserves)
+    }
+
+    /// Get protocol-wide solvency: (collateral USD, debt USD, collateral >= debt)
+    ///
+    /// Values are in 14-decimal USD at current oracle prices. Collateral is
+    /// the total deposited in each listed asset, so no per-user iteration is
+    /// needed; delisted assets are left out. Debt is outstanding principal
+    /// across all borrowers and excludes accrued interest, which is only
+    /// tracked per position.
+    pub fn get_solvency(env: Env) -> Result<(i128, i128, bool), PoolError> {
+        let assets: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CollateralAssets)
+            .unwrap_or(Vec::new(&env));
+
+        let mut collateral_value: i128 = 0;
+        for asset in assets.iter() {
+            let config: CollateralConfig = match env.storage().persistent().get(&asset) {
+                Some(config) => config,
+                None => continue,
+            };
+            let deposits: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalDeposits(asset))
+                .unwrap_or(0);
+            collateral_value += Self::get_asset_value(&env, &config, deposits)?;
+        }
+
+        let total_borrows: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalBorrows)
+            .unwrap_or(0);
+        let debt_value = oracle::calculate_value(total_borrows, Self::borrow_unit_price(&env)?);
+
+        Ok((collateral_value, debt_value, collateral_value >= debt_value))
     }
 
-    /// Get total borrows
+    /// Get total borrows (borrow-token units)
     pub fn get_total_borrows(env: Env) -> i128 {
-        env.storage()
+        let total_borrows: i128 = env
+            .storage()
             .instance()
             .get(&DataKey::TotalBorrows)
-            .unwrap_or(0)
+            .unwrap_or(0);
+        Self::borrow_from_accounting(&env, total_borrows)
+    }
+
+    /// Get outstanding bad debt (borrow-token units)
+    pub fn get_bad_debt(env: Env) -> i128 {
+        let bad_debt: i128 = env.storage().instance().get(&DataKey::BadDebt).unwrap_or(0);
+        Self::borrow_from_accounting(&env, bad_debt)
+    }
+
+    /// Get accrued protocol fees (borrow-token units)
+    pub fn get_protocol_fees(env: Env) -> i128 {
+        let fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProtocolFees)
+            .unwrap_or(0);
+        Self::borrow_from_accounting(&env, fees)
+    }
+
+    /// Get borrow token decimals
+    pub fn get_borrow_decimals(env: Env) -> u32 {
+        Self::borrow_decimals(&env)
     }
 
     /// Get current interest rate
@@ -762,6 +1636,46 @@ impl VantisPoolContract {
         Self::get_current_interest_rate(&env)
     }
 
+    /// Get the current interest rate at `get_rate_decimals` extra decimals
+    ///
+    /// Equal to `get_interest_rate` unless the rate parameters were set with
+    /// extra precision, e.g. 150 = 1.5 bp at 2 extra decimals.
+    pub fn get_interest_rate_precise(env: Env) -> Result<i128, PoolError> {
+        Self::current_precise_rate(&env)
+    }
+
+    /// Get current utilization (basis points, borrows / (reserves + borrows))
+    pub fn get_utilization(env: Env) -> u32 {
+        Self::current_utilization(&env)
+    }
+
+    /// Get utilization, borrow rate and supply rate in one call
+    ///
+    /// # Returns
+    /// `(utilization, borrow_rate, supply_rate)` in basis points, all derived
+    /// from the same utilization read
+    pub fn get_rates(env: Env) -> Result<(u32, u32, u32), PoolError> {
+        Self::current_rates(&env)
+    }
+
+    /// Get utilization, rates and liquidity totals in one call
+    pub fn get_pool_state(env: Env) -> Result<PoolState, PoolError> {
+        let total_reserves = Self::get_reserves(env.clone());
+        let total_borrows = Self::get_total_borrows(env.clone());
+        let total_liquidity = total_reserves + total_borrows;
+
+        let (utilization, borrow_rate, supply_rate) = Self::current_rates(&env)?;
+
+        Ok(PoolState {
+            utilization,
+            borrow_rate,
+            supply_rate,
+            total_reserves,
+            total_borrows,
+            total_liquidity,
+        })
+    }
+
     /// Get Blend adapter address
     pub fn get_blend_pool(env: Env) -> Result<Address, PoolError> {
         env.storage()
@@ -772,38 +1686,1445 @@ impl VantisPoolContract {
 
     // ============ Internal Functions ============
 
-    fn require_admin(env: &Env, caller: &Address) -> Result<(), PoolError> {
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        if *caller != admin {
-            return Err(PoolError::Unauthorized);
+    /// Apply a collateral deposit the user has already authorized
+    fn apply_deposit(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), PoolError> {
+        if amount <= 0 {
+            return Err(PoolError::InvalidAmount);
         }
-        Ok(())
-    }
 
-    fn require_asset_supported(env: &Env, asset: &Address) -> Result<(), PoolError> {
-        let assets: Vec<Address> = env
+        Self::require_asset_supported(&env, &asset)?;
+        let internal_amount = Self::to_accounting(amount, Self::collateral_decimals(&env, &asset)?);
+
+        let mut user_collateral: Map<Address, i128> = env
             .storage()
-            .instance()
-            .get(&DataKey::CollateralAssets)
-            .unwrap_or(Vec::new(env));
+            .persistent()
+            .get(&DataKey::Collateral(user.clone()))
+            .unwrap_or(Map::new(&env));
 
-        for a in assets.iter() {
-            if a == *asset {
-                return Ok(());
+        // A new asset type counts against the per-user cap; top-ups do not
+        if !user_collateral.contains_key(asset.clone()) {
+            let max_assets: u32 = env
+                .storage()
+                .instance()
+                .get(&DataKey::MaxUserCollateralAssets)
+                .unwrap_or(DEFAULT_MAX_USER_COLLATERAL_ASSETS);
+            if user_collateral.len() >= max_assets {
+                return Err(PoolError::TooManyCollateralAssets);
             }
         }
-        Err(PoolError::AssetNotSupported)
-    }
-
-    /// Set the risk engine contract address
-    pub fn set_risk_engine(env: Env, caller: Address, risk_engine: Address) -> Result<(), PoolError> {
-        caller.require_auth();
-        Self::require_admin(&env, &caller)?;
-        env.storage().instance().set(&DataKey::RiskEngine, &risk_engine);
-        Ok(())
-    }
+
+        // Get Blend adapter address
+        let blend_pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendPool)
+            .ok_or(PoolError::BlendAdapterError)?;
+
+        // Transfer tokens from user to this contract first
+        let token_client = token::Client::new(&env, &asset);
+        if token_client.balance(&user) < amount {
+            return Err(PoolError::InvalidAmount);
+        }
+        token_client.transfer(&user, &env.current_contract_address(), &amount);
+
+        // Approve Blend adapter to spend the tokens
+        // Set expiration to current ledger + 1000 ledgers (about 1.4 hours)
+        let expiration_ledger = env.ledger().sequence() + 1000;
+        token_client.approve(&env.current_contract_address(), &blend_pool, &amount, &expiration_ledger);
+
+        // Route through Blend adapter by invoking its deposit_collateral function
+        // Note: In production, this would use the blend-adapter contract client
+        // For now, we track the deposit locally and emit an event
+        Self::publish_event(
+            &env,
+            (symbol_short!("blend"), symbol_short!("deposit")),
+            (&user, &asset, amount),
+        );
+
+        // Update user's collateral position locally for tracking
+        let current = user_collateral.get(asset.clone()).unwrap_or(0);
+        user_collateral.set(asset.clone(), current + internal_amount);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Collateral(user.clone()), &user_collateral);
+
+        // Update total deposits
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalDeposits(asset.clone()))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalDeposits(asset.clone()), &(total + internal_amount));
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("deposit"), user.clone()),
+            (&asset, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Apply a collateral withdrawal the user has already authorized
+    fn apply_withdraw(
+        env: Env,
+        user: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<(), PoolError> {
+        if amount <= 0 {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        let internal_amount = Self::to_accounting(amount, Self::collateral_decimals(&env, &asset)?);
+
+        // Get user's collateral
+        let mut user_collateral: Map<Address, i128> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Collateral(user.clone()))
+            .ok_or(PoolError::InsufficientCollateral)?;
+
+        let current = user_collateral.get(asset.clone()).unwrap_or(0);
+        if current < internal_amount {
+            return Err(PoolError::InsufficientCollateral);
+        }
+
+        let health_before = Self::calculate_health_factor(&env, &user)?;
+
+        // Check if withdrawal would make position unhealthy
+        let new_amount = current - internal_amount;
+        // Drop emptied assets so they stop counting against the per-user cap
+        if new_amount == 0 {
+            user_collateral.remove(asset.clone());
+        } else {
+            user_collateral.set(asset.clone(), new_amount);
+        }
+
+        // Temporarily update to check health factor
+        env.storage()
+            .persistent()
+            .set(&DataKey::Collateral(user.clone()), &user_collateral);
+
+        let health_factor = Self::calculate_health_factor(&env, &user)?;
+        if health_factor < Self::hf_scale(&env) {
+            // HF < 1.0
+            // Revert the change
+            user_collateral.set(asset.clone(), current);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Collateral(user.clone()), &user_collateral);
+            return Err(PoolError::WithdrawalWouldLiquidate);
+        }
+
+        // Force large exits to happen gradually; debt-free positions stay unlimited
+        let max_drop = Self::get_max_withdrawal_hf_drop(env.clone());
+        if max_drop > 0 && health_before != i128::MAX && health_before - health_factor > max_drop {
+            user_collateral.set(asset.clone(), current);
+            env.storage()
+                .persistent()
+                .set(&DataKey::Collateral(user.clone()), &user_collateral);
+            return Err(PoolError::WithdrawalExceedsHfDrop);
+        }
+
+        // Get Blend adapter address
+        let _blend_pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendPool)
+            .ok_or(PoolError::BlendAdapterError)?;
+
+        // Route through Blend adapter by invoking its withdraw_collateral function
+        // Note: In production, this would use the blend-adapter contract client
+        // For now, we track the withdrawal locally and emit an event
+        Self::publish_event(
+            &env,
+            (symbol_short!("blend"), symbol_short!("withdraw")),
+            (&user, &asset, amount),
+        );
+
+        // Update total deposits
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalDeposits(asset.clone()))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalDeposits(asset.clone()), &(total - internal_amount));
+
+        // The Blend withdrawal is not wired yet, so the tokens are still
+        // held here and must not look like surplus
+        let unpaid: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::UnpaidWithdrawals(asset.clone()))
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::UnpaidWithdrawals(asset.clone()), &(unpaid + internal_amount));
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("withdraw"), user.clone()),
+            (&asset, amount),
+        );
+
+        Ok(())
+    }
+
+    /// Apply a borrow the user has already authorized
+    fn apply_borrow(env: Env, user: Address, amount: i128) -> Result<(), PoolError> {
+        if amount <= 0 {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        Self::require_borrow_asset_pegged(&env)?;
+
+        let requested = Self::borrow_to_accounting(&env, amount);
+        let fee_bp = Self::get_origination_fee_bp(env.clone());
+        let fee = requested * fee_bp as i128 / 10000;
+        let internal_amount = match Self::get_origination_fee_mode(env.clone()) {
+            OriginationFeeMode::Deduct => requested,
+            OriginationFeeMode::AddToDebt => requested + fee,
+        };
+
+        // Accrue interest first
+        Self::accrue_interest(&env, &user)?;
+
+        // Check pool liquidity
+        let reserves: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PoolReserves)
+            .unwrap_or(0);
+
+        if reserves < internal_amount {
+            return Err(PoolError::InsufficientLiquidity);
+        }
+
+        // Enforce the borrow asset's debt ceiling
+        let borrow_token: Address = env.storage().instance().get(&DataKey::XlmToken).unwrap();
+        Self::require_borrow_allowed(&env, &user, &borrow_token)?;
+        if let Some(ceiling) = env
+            .storage()
+            .instance()
+            .get::<_, i128>(&DataKey::DebtCeiling(borrow_token))
+        {
+            let outstanding: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalBorrows)
+                .unwrap_or(0);
+            if outstanding + internal_amount > ceiling {
+                return Err(PoolError::BorrowCapExceeded);
+            }
+        }
+
+        // Refuse dust positions that would be uneconomical to liquidate
+        let min_collateral_value = Self::get_min_collateral_value(env.clone());
+        if min_collateral_value > 0
+            && Self::calculate_weighted_collateral(&env, &user, 0)? <= min_collateral_value
+        {
+            return Err(PoolError::CollateralTooSmall);
+        }
+
+        // Get user's borrowing capacity
+        let borrow_capacity = Self::calculate_borrow_capacity(&env, &user, 0)?;
+
+        // Get current borrow
+        let mut borrow_data: BorrowData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Borrow(user.clone()))
+            .unwrap_or(BorrowData {
+                principal: 0,
+                accrued_interest: 0,
+                last_accrual: env.ledger().timestamp(),
+            });
+
+        let total_debt = borrow_data.principal + borrow_data.accrued_interest;
+        if total_debt + internal_amount > borrow_capacity {
+            return Err(PoolError::InsufficientCollateral);
+        }
+
+        // Get Blend adapter address
+        let _blend_pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendPool)
+            .ok_or(PoolError::BlendAdapterError)?;
+
+        // Route through Blend adapter by invoking its borrow function
+        // Note: In production, this would use the blend-adapter contract client
+        // For now, we track the borrow locally and emit an event
+        Self::publish_event(
+            &env,
+            (symbol_short!("blend"), symbol_short!("borrow")),
+            (&user, Self::borrow_from_accounting(&env, internal_amount - fee)),
+        );
+
+        // Update borrow position
+        let previous_principal = borrow_data.principal;
+        borrow_data.principal += internal_amount;
+        borrow_data.last_accrual = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Borrow(user.clone()), &borrow_data);
+
+        // Update pool state
+        env.storage()
+            .instance()
+            .set(&DataKey::PoolReserves, &(reserves - internal_amount));
+
+        let total_borrows: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalBorrows)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalBorrows, &(total_borrows + internal_amount));
+
+        if fee > 0 {
+            let fees: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::ProtocolFees)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::ProtocolFees, &(fees + fee));
+
+            let origination_fees: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::OriginationFees)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::OriginationFees, &(origination_fees + fee));
+
+            Self::publish_event(
+                &env,
+                (symbol_short!("borrow"), symbol_short!("fee")),
+                (&user, Self::borrow_from_accounting(&env, fee)),
+            );
+        }
+
+        // Fold the rate this draw originates at into the user's blended rate
+        let origination_rate = Self::get_current_interest_rate(&env)?;
+        let previous_rate: u32 = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BorrowRate(user.clone()))
+            .unwrap_or(0);
+        let blended_rate = (previous_principal * previous_rate as i128
+            + internal_amount * origination_rate as i128)
+            / borrow_data.principal;
+        env.storage()
+            .persistent()
+            .set(&DataKey::BorrowRate(user.clone()), &(blended_rate as u32));
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("borrow"), user.clone()),
+            amount,
+        );
+        Self::emit_utilization_changed(&env);
+
+        Ok(())
+    }
+
+    /// Apply a repayment the user has already authorized
+    fn apply_repay(env: Env, user: Address, amount: i128) -> Result<(), PoolError> {
+        if amount <= 0 {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        // Accrue interest first
+        Self::accrue_interest(&env, &user)?;
+
+        let mut borrow_data: BorrowData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Borrow(user.clone()))
+            .ok_or(PoolError::NoBorrowPosition)?;
+
+        let total_debt = borrow_data.principal + borrow_data.accrued_interest;
+        if total_debt == 0 {
+            return Err(PoolError::NoBorrowPosition);
+        }
+
+        let internal_amount = Self::borrow_to_accounting(&env, amount);
+        let repay_amount = if internal_amount > total_debt { total_debt } else { internal_amount };
+        let repay_tokens = Self::borrow_from_accounting(&env, repay_amount);
+
+        // Get Blend adapter address
+        let _blend_pool: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::BlendPool)
+            .ok_or(PoolError::BlendAdapterError)?;
+
+        // Route through Blend adapter by invoking its repay function
+        // Note: In production, this would use the blend-adapter contract client
+        // For now, we track the repay locally and emit an event
+        Self::publish_event(
+            &env,
+            (symbol_short!("blend"), symbol_short!("repay")),
+            (&user, repay_tokens),
+        );
+
+        // Apply repayment according to the user's (or the pool's) ordering
+        let ordering = Self::get_user_repay_ordering(env.clone(), user.clone());
+        let interest_before = borrow_data.accrued_interest;
+        let principal_before = borrow_data.principal;
+
+        match ordering {
+            RepayOrdering::InterestFirst => {
+                if repay_amount <= borrow_data.accrued_interest {
+                    borrow_data.accrued_interest -= repay_amount;
+                } else {
+                    let remaining = repay_amount - borrow_data.accrued_interest;
+                    borrow_data.accrued_interest = 0;
+                    borrow_data.principal -= remaining;
+                }
+            }
+            RepayOrdering::PrincipalFirst => {
+                if repay_amount <= borrow_data.principal {
+                    borrow_data.principal -= repay_amount;
+                } else {
+                    let remaining = repay_amount - borrow_data.principal;
+                    borrow_data.principal = 0;
+                    borrow_data.accrued_interest -= remaining;
+                }
+            }
+        }
+
+        borrow_data.last_accrual = env.ledger().timestamp();
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Borrow(user.clone()), &borrow_data);
+
+        // Update pool state
+        let interest_paid = interest_before - borrow_data.accrued_interest;
+        Self::credit_repayment(&env, repay_amount, interest_paid);
+
+        // Interest is never added to total borrows, so only principal leaves it
+        let total_borrows: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalBorrows)
+            .unwrap_or(0);
+        let principal_paid = principal_before - borrow_data.principal;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalBorrows, &(total_borrows - principal_paid));
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("repay"), user.clone()),
+            repay_tokens,
+        );
+        Self::emit_utilization_changed(&env);
+
+        Ok(())
+    }
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), PoolError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *caller != admin {
+            return Err(PoolError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Reject wiring a dependency to the admin or to the pool itself
+    fn require_external_contract(env: &Env, address: &Address) -> Result<(), PoolError> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        if *address == admin || *address == env.current_contract_address() {
+            return Err(PoolError::InvalidParams);
+        }
+        Ok(())
+    }
+
+    fn require_asset_supported(env: &Env, asset: &Address) -> Result<(), PoolError> {
+        let assets: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CollateralAssets)
+            .unwrap_or(Vec::new(env));
+
+        for a in assets.iter() {
+            if a == *asset {
+                return Ok(());
+            }
+        }
+        Err(PoolError::AssetNotSupported)
+    }
+
+    /// Decimals of a supported collateral asset
+    fn collateral_decimals(env: &Env, asset: &Address) -> Result<u32, PoolError> {
+        let config: CollateralConfig = env
+            .storage()
+            .persistent()
+            .get(asset)
+            .ok_or(PoolError::AssetNotSupported)?;
+        Ok(config.decimals)
+    }
+
+    /// Decimals of the borrow token
+    fn borrow_decimals(env: &Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::BorrowDecimals)
+            .unwrap_or(DEFAULT_BORROW_DECIMALS)
+    }
+
+    /// Convert a token amount to accounting units
+    fn to_accounting(amount: i128, decimals: u32) -> i128 {
+        scale_decimals(amount, decimals, ACCOUNTING_DECIMALS)
+    }
+
+    /// Convert accounting units back to a token amount (truncating)
+    fn from_accounting(amount: i128, decimals: u32) -> i128 {
+        scale_decimals(amount, ACCOUNTING_DECIMALS, decimals)
+    }
+
+    fn borrow_to_accounting(env: &Env, amount: i128) -> i128 {
+        Self::to_accounting(amount, Self::borrow_decimals(env))
+    }
+
+    fn borrow_from_accounting(env: &Env, amount: i128) -> i128 {
+        Self::from_accounting(amount, Self::borrow_decimals(env))
+    }
+
+    /// Set the maximum distinct collateral assets a user may hold (admin only)
+    pub fn set_max_user_collateral_assets(
+        env: Env,
+        caller: Address,
+        max_assets: u32,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if max_assets == 0 {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxUserCollateralAssets, &max_assets);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "max_user_assets"), max_assets),
+        );
+
+        Ok(())
+    }
+
+    /// Get the maximum distinct collateral assets a user may hold
+    pub fn get_max_user_collateral_assets(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxUserCollateralAssets)
+            .unwrap_or(DEFAULT_MAX_USER_COLLATERAL_ASSETS)
+    }
+
+    /// Set borrow token decimals (admin only)
+    ///
+    /// Stored amounts are kept in `ACCOUNTING_DECIMALS`, so this only changes
+    /// how token amounts are converted at the contract boundary.
+    pub fn set_borrow_decimals(env: Env, caller: Address, decimals: u32) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if decimals > ACCOUNTING_DECIMALS {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::BorrowDecimals, &decimals);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "borrow_decimals"), decimals),
+        );
+
+        Ok(())
+    }
+
+    /// Set the risk engine contract address
+    ///
+    /// Rejects the admin and the pool's own address with `InvalidParams`.
+    /// Emits `("config", "updated")` with `(key, previous, new)`, where
+    /// `previous` is `None` if no address was set before.
+    pub fn set_risk_engine(env: Env, caller: Address, risk_engine: Address) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        Self::require_external_contract(&env, &risk_engine)?;
+
+        let previous: Option<Address> = env.storage().instance().get(&DataKey::RiskEngine);
+        env.storage().instance().set(&DataKey::RiskEngine, &risk_engine);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("updated")),
+            (Symbol::new(&env, "risk_engine"), previous, risk_engine),
+        );
+
+        Ok(())
+    }
+
+    /// Set the external interest rate model contract
+    ///
+    /// Once set, `get_current_interest_rate` queries the model instead of
+    /// the inline `InterestParams` kink curve. The model is probed once and
+    /// rejected with `RateModelError` if it cannot quote a rate.
+    pub fn set_interest_rate_model(
+        env: Env,
+        caller: Address,
+        model: Address,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        Self::require_external_contract(&env, &model)?;
+
+        if !matches!(
+            rate_model::RateModelClient::new(&env, &model).try_rate(&0),
+            Ok(Ok(_))
+        ) {
+            return Err(PoolError::RateModelError);
+        }
+
+        env.storage().instance().set(&DataKey::InterestRateModel, &model);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "rate_model"), model),
+        );
+
+        Ok(())
+    }
+
+    /// Replace the inline interest rate parameters (admin only)
+    ///
+    /// `rate_decimals` sets how many decimals beyond basis points the rates
+    /// in `params` carry (0 = basis points), so that rates finer than
+    /// 0.01%/year accrue. Basis-point getters round down.
+    ///
+    /// # Arguments
+    /// * `params` - Kink-curve parameters; utilization stays in basis points
+    /// * `rate_decimals` - Extra rate decimals, at most `MAX_RATE_DECIMALS`
+    pub fn set_interest_params(
+        env: Env,
+        caller: Address,
+        params: InterestRateParams,
+        rate_decimals: u32,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if rate_decimals > MAX_RATE_DECIMALS
+            || params.optimal_utilization == 0
+            || params.optimal_utilization >= 10000
+        {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::InterestParams, &params);
+        env.storage().instance().set(&DataKey::RateDecimals, &rate_decimals);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "interest_params"), rate_decimals),
+        );
+
+        Ok(())
+    }
+
+    /// Get the decimals interest rate parameters carry beyond basis points (0 by default)
+    pub fn get_rate_decimals(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RateDecimals)
+            .unwrap_or(0)
+    }
+
+    /// Get the external interest rate model contract, if any
+    pub fn get_interest_rate_model(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::InterestRateModel)
+    }
+
+    /// Write off an insolvent position as bad debt (admin only)
+    ///
+    /// Only allowed once the position's collateral, at full oracle value, no
+    /// longer covers its debt. The remaining collateral is sent to `to` for
+    /// recovery, the user's debt is cleared and its principal is added to
+    /// `BadDebt`, where `cover_bad_debt` can absorb it. Accrued interest
+    /// never reached total borrows, so it is simply forgiven.
+    ///
+    /// # Returns
+    /// Principal written off (borrow-token units)
+    ///
+    /// # Errors
+    /// - `NoBorrowPosition`: The user has no principal outstanding
+    /// - `PositionSolvent`: The collateral still covers the debt
+    pub fn write_off_bad_debt(
+        env: Env,
+        caller: Address,
+        user: Address,
+        to: Address,
+    ) -> Result<i128, PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        Self::accrue_interest(&env, &user)?;
+
+        let borrow_data: BorrowData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Borrow(user.clone()))
+            .unwrap_or_default();
+        if borrow_data.principal <= 0 {
+            return Err(PoolError::NoBorrowPosition);
+        }
+
+        let breakdown = Self::calculate_collateral_breakdown(&env, &user)?;
+        let mut collateral_value: i128 = 0;
+        for (_, _, value, _) in breakdown.iter() {
+            collateral_value += value;
+        }
+        let total_debt = borrow_data.principal + borrow_data.accrued_interest;
+        let debt_value = oracle::calculate_value(total_debt, Self::borrow_unit_price(&env)?);
+        if collateral_value >= debt_value {
+            return Err(PoolError::PositionSolvent);
+        }
+
+        // Hand what is left of the collateral over for recovery
+        for (asset, amount, _, _) in breakdown.iter() {
+            if amount <= 0 {
+                continue;
+            }
+
+            let token_amount =
+                Self::from_accounting(amount, Self::collateral_decimals(&env, &asset)?);
+            token::Client::new(&env, &asset).transfer(
+                &env.current_contract_address(),
+                &to,
+                &token_amount,
+            );
+
+            let total: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalDeposits(asset.clone()))
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalDeposits(asset.clone()), &(total - amount));
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Collateral(user.clone()));
+
+        // Clear the position so a later repay cannot reduce total borrows again
+        env.storage().persistent().set(
+            &DataKey::Borrow(user.clone()),
+            &BorrowData {
+                principal: 0,
+                accrued_interest: 0,
+                last_accrual: env.ledger().timestamp(),
+            },
+        );
+
+        let bad_debt: i128 = env.storage().instance().get(&DataKey::BadDebt).unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::BadDebt, &(bad_debt + borrow_data.principal));
+
+        let written_off = Self::borrow_from_accounting(&env, borrow_data.principal);
+        Self::publish_event(
+            &env,
+            (symbol_short!("baddebt"), symbol_short!("written")),
+            (&user, written_off, &to),
+        );
+
+        Ok(written_off)
+    }
+
+    /// Cover bad debt from protocol fees, socializing any remainder (admin only)
+    ///
+    /// Protocol fees are moved back into reserves first. Whatever fees cannot
+    /// cover is written off against total borrows, so suppliers absorb the
+    /// loss through a smaller pool.
+    ///
+    /// # Arguments
+    /// * `amount` - Bad debt to cover (borrow-token units)
+    pub fn cover_bad_debt(env: Env, caller: Address, amount: i128) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        let internal_amount = Self::borrow_to_accounting(&env, amount);
+        let bad_debt: i128 = env.storage().instance().get(&DataKey::BadDebt).unwrap_or(0);
+
+        if internal_amount <= 0 || internal_amount > bad_debt {
+            return Err(PoolError::InvalidAmount);
+        }
+
+        let fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ProtocolFees)
+            .unwrap_or(0);
+        let from_fees = if fees < internal_amount { fees } else { internal_amount };
+        let socialized = internal_amount - from_fees;
+
+        let reserves: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PoolReserves)
+            .unwrap_or(0);
+        let total_borrows: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalBorrows)
+            .unwrap_or(0);
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ProtocolFees, &(fees - from_fees));
+        env.storage()
+            .instance()
+            .set(&DataKey::PoolReserves, &(reserves + from_fees));
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalBorrows, &(total_borrows - internal_amount));
+        env.storage()
+            .instance()
+            .set(&DataKey::BadDebt, &(bad_debt - internal_amount));
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("baddebt"), symbol_short!("covered")),
+            (
+                Self::borrow_from_accounting(&env, from_fees),
+                Self::borrow_from_accounting(&env, socialized),
+            ),
+        );
+
+        Ok(())
+    }
+
+    /// Transfer unaccounted token balance to a recipient (admin only)
+    ///
+    /// Only the surplus above tracked accounting is moved, as reported by
+    /// `check_solvency`. Outstanding loans and withdrawn collateral are still
+    /// held by the pool and count as accounted, so they are never swept.
+    ///
+    /// # Returns
+    /// Amount swept (0 if there is no surplus)
+    pub fn sweep(env: Env, caller: Address, asset: Address, to: Address) -> Result<i128, PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        let (accounted, actual) = Self::check_solvency(env.clone(), asset.clone())?;
+        let surplus = actual - accounted;
+        if surplus <= 0 {
+            return Ok(0);
+        }
+
+        token::Client::new(&env, &asset).transfer(&env.current_contract_address(), &to, &surplus);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("sweep"), asset),
+            (to, surplus),
+        );
+
+        Ok(surplus)
+    }
+
+    /// Compare tracked accounting for an asset with the pool's actual balance
+    ///
+    /// The accounted amount is total deposits plus withdrawn-but-unpaid
+    /// collateral for the asset, and reserves, protocol fees and total
+    /// borrows for the borrow token, less origination fees, which are booked
+    /// as fees but never received. Actual exceeding accounted is
+    /// sweepable surplus; accounted exceeding actual signals a shortfall.
+    /// Everything is compared in token units, never at oracle prices.
+    ///
+    /// # Returns
+    /// `(accounted, actual)` in the asset's token units
+    ///
+    /// # Errors
+    /// - `InvalidParams`: The pool has not been initialized
+    pub fn check_solvency(env: Env, asset: Address) -> Result<(i128, i128), PoolError> {
+        let deposits: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TotalDeposits(asset.clone()))
+            .unwrap_or(0);
+        let unpaid: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::UnpaidWithdrawals(asset.clone()))
+            .unwrap_or(0);
+        let mut accounted = match Self::collateral_decimals(&env, &asset) {
+            Ok(decimals) => Self::from_accounting(deposits + unpaid, decimals),
+            Err(_) => 0,
+        };
+
+        let borrow_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::XlmToken)
+            .ok_or(PoolError::InvalidParams)?;
+        if asset == borrow_token {
+            let reserves: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::PoolReserves)
+                .unwrap_or(0);
+            let fees: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::ProtocolFees)
+                .unwrap_or(0);
+            // Borrows are not paid out through Blend yet, so loans stay here
+            let borrows: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::TotalBorrows)
+                .unwrap_or(0);
+            let origination_fees: i128 = env
+                .storage()
+                .instance()
+                .get(&DataKey::OriginationFees)
+                .unwrap_or(0);
+            let held = reserves + fees + borrows - origination_fees;
+            accounted += Self::borrow_from_accounting(&env, held);
+        }
+
+        let actual = token::Client::new(&env, &asset).balance(&env.current_contract_address());
+
+        Ok((accounted, actual))
+    }
+
+    /// Set how partial repayments are split between interest and principal
+    pub fn set_repay_ordering(
+        env: Env,
+        caller: Address,
+        ordering: RepayOrdering,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+        env.storage().instance().set(&DataKey::RepayOrdering, &ordering);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "repay_order"), ordering),
+        );
+
+        Ok(())
+    }
+
+    /// Get the repayment ordering (interest-first by default)
+    pub fn get_repay_ordering(env: Env) -> RepayOrdering {
+        env.storage()
+            .instance()
+            .get(&DataKey::RepayOrdering)
+            .unwrap_or_default()
+    }
+
+    /// Choose how the user's own partial repayments are split
+    ///
+    /// # Arguments
+    /// * `ordering` - Ordering for this user, or `None` to follow the pool's
+    pub fn set_user_repay_ordering(env: Env, user: Address, ordering: Option<RepayOrdering>) {
+        user.require_auth();
+
+        let key = DataKey::UserRepayOrdering(user.clone());
+        match ordering {
+            Some(ordering) => env.storage().persistent().set(&key, &ordering),
+            None => env.storage().persistent().remove(&key),
+        }
+
+        Self::publish_event(&env, (Symbol::new(&env, "repay_order"), user), ordering);
+    }
+
+    /// Get the repayment ordering applied to a user (their own, else the pool's)
+    pub fn get_user_repay_ordering(env: Env, user: Address) -> RepayOrdering {
+        env.storage()
+            .persistent()
+            .get(&DataKey::UserRepayOrdering(user))
+            .unwrap_or_else(|| Self::get_repay_ordering(env.clone()))
+    }
+
+    /// Set the longest period a single `poke` accrues (admin only)
+    ///
+    /// # Arguments
+    /// * `max_accrual_period` - Seconds per call (0 = accrue the full backlog)
+    pub fn set_max_accrual_period(
+        env: Env,
+        caller: Address,
+        max_accrual_period: u64,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxAccrualPeriod, &max_accrual_period);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "max_accrual_period"), max_accrual_period),
+        );
+
+        Ok(())
+    }
+
+    /// Set the largest health-factor drop a single withdrawal may cause (admin only)
+    ///
+    /// # Arguments
+    /// * `max_drop` - Drop at `get_hf_scale` (e.g. 5000 = 0.5 by default), 0 disables
+    pub fn set_max_withdrawal_hf_drop(
+        env: Env,
+        caller: Address,
+        max_drop: i128,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if max_drop < 0 {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxWithdrawalHfDrop, &max_drop);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "max_withdrawal_hf_drop"), max_drop),
+        );
+
+        Ok(())
+    }
+
+    /// Set the cap on a position's accrued interest (admin only)
+    ///
+    /// Interest stops accruing once it reaches `max_ratio_bp` of the
+    /// principal, and a `max_interest_reached` event is emitted.
+    ///
+    /// # Arguments
+    /// * `max_ratio_bp` - Cap in basis points of principal (e.g. 100000 = 10x)
+    pub fn set_max_interest_ratio(
+        env: Env,
+        caller: Address,
+        max_ratio_bp: u32,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if max_ratio_bp == 0 {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxInterestRatio, &max_ratio_bp);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "max_interest_ratio"), max_ratio_bp),
+        );
+
+        Ok(())
+    }
+
+    /// Get the cap on accrued interest in basis points of principal (10x by default)
+    pub fn get_max_interest_ratio(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxInterestRatio)
+            .unwrap_or(DEFAULT_MAX_INTEREST_RATIO_BP)
+    }
+
+    /// Set the minimum gap between collateral factor and liquidation threshold (admin only)
+    ///
+    /// Applies to collateral assets added afterwards (including ramp end
+    /// factors) and lowers the factors used by `get_safe_borrow_limit`.
+    ///
+    /// # Arguments
+    /// * `buffer_bp` - Gap in basis points (e.g. 500 = 5%), 0 disables
+    pub fn set_liquidation_buffer(
+        env: Env,
+        caller: Address,
+        buffer_bp: u32,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if buffer_bp >= 10000 {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::LiquidationBuffer, &buffer_bp);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "liquidation_buffer"), buffer_bp),
+        );
+
+        Ok(())
+    }
+
+    /// Get the minimum gap between collateral factor and liquidation threshold (0 = none)
+    pub fn get_liquidation_buffer(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::LiquidationBuffer)
+            .unwrap_or(0)
+    }
+
+    /// Set the weighted collateral value a user must exceed to borrow (admin only)
+    ///
+    /// Keeps tiny positions, which leave liquidation dust, from borrowing.
+    /// Only checked when borrowing; existing positions are not affected.
+    ///
+    /// # Arguments
+    /// * `min_value` - Floor in 14-decimal USD, 0 disables
+    pub fn set_min_collateral_value(
+        env: Env,
+        caller: Address,
+        min_value: i128,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if min_value < 0 {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MinCollateralValue, &min_value);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "min_collateral_value"), min_value),
+        );
+
+        Ok(())
+    }
+
+    /// Get the weighted collateral value required to borrow (0 = none)
+    pub fn get_min_collateral_value(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinCollateralValue)
+            .unwrap_or(0)
+    }
+
+    /// Get the largest health-factor drop a single withdrawal may cause (0 = unlimited)
+    pub fn get_max_withdrawal_hf_drop(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxWithdrawalHfDrop)
+            .unwrap_or(0)
+    }
+
+    /// Get the longest period a single `poke` accrues (0 = uncapped)
+    pub fn get_max_accrual_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxAccrualPeriod)
+            .unwrap_or(0)
+    }
+
+    /// Set the fixed-point scale of health factors (admin only)
+    ///
+    /// `scale` represents a health factor of 1.0 and must be a power of ten
+    /// from 10000 (basis points, the default) up to 1e12. Health thresholds
+    /// are defined in basis points and rescaled to match.
+    pub fn set_hf_scale(env: Env, caller: Address, scale: i128) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if !is_valid_hf_scale(scale) {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage().instance().set(&DataKey::HfScale, &scale);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "hf_scale"), scale),
+        );
+
+        Ok(())
+    }
+
+    /// Get the fixed-point scale of health factors
+    pub fn get_hf_scale(env: Env) -> i128 {
+        Self::hf_scale(&env)
+    }
+
+    /// Set the peg band the borrow asset must trade within (admin only)
+    ///
+    /// While set, `borrow` fails with `OracleError` if the oracle price of
+    /// `peg.symbol` deviates from $1 by more than `peg.max_deviation_bp`.
+    /// Repayments are never blocked. `None` disables the check.
+    pub fn set_peg_check(
+        env: Env,
+        caller: Address,
+        peg: Option<PegCheck>,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        match &peg {
+            Some(peg) => {
+                if peg.max_deviation_bp == 0 || peg.max_deviation_bp > 10000 {
+                    return Err(PoolError::InvalidParams);
+                }
+                env.storage().instance().set(&DataKey::PegCheck, peg);
+            }
+            None => env.storage().instance().remove(&DataKey::PegCheck),
+        }
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "peg_check"), peg),
+        );
+
+        Ok(())
+    }
+
+    /// Get the peg band the borrow asset must trade within
+    pub fn get_peg_check(env: Env) -> Option<PegCheck> {
+        env.storage().instance().get(&DataKey::PegCheck)
+    }
+
+    /// Get the sequence number of the last published event (0 if none)
+    pub fn get_event_seq(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::EventSeq).unwrap_or(0)
+    }
+
+    /// Get the deployment tag prepended to event topics, if any
+    pub fn get_instance_tag(env: Env) -> Option<Symbol> {
+        env.storage().instance().get(&DataKey::InstanceTag)
+    }
+
+    /// Set the maximum outstanding borrows of a borrow asset (admin only)
+    ///
+    /// # Arguments
+    /// * `asset` - Borrow asset the ceiling applies to
+    /// * `ceiling` - Debt ceiling in the asset's token units (0 removes it)
+    pub fn set_debt_ceiling(
+        env: Env,
+        caller: Address,
+        asset: Address,
+        ceiling: i128,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if ceiling < 0 {
+            return Err(PoolError::InvalidParams);
+        }
+
+        let key = DataKey::DebtCeiling(asset.clone());
+        if ceiling == 0 {
+            env.storage().instance().remove(&key);
+        } else {
+            env.storage()
+                .instance()
+                .set(&key, &Self::borrow_to_accounting(&env, ceiling));
+        }
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "debt_ceiling"), (asset, ceiling)),
+        );
+
+        Ok(())
+    }
+
+    /// Restrict which borrow assets a collateral asset may back (admin only)
+    ///
+    /// Users holding a restricted collateral cannot borrow assets outside its
+    /// list. `None` lifts the restriction.
+    ///
+    /// # Arguments
+    /// * `collateral` - Listed collateral asset
+    /// * `borrow_assets` - Borrow assets the collateral may back
+    pub fn set_allowed_borrow_assets(
+        env: Env,
+        caller: Address,
+        collateral: Address,
+        borrow_assets: Option<Vec<Address>>,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if Self::get_collateral_config(env.clone(), collateral.clone()).is_none() {
+            return Err(PoolError::AssetNotSupported);
+        }
+
+        let key = DataKey::AllowedBorrowAssets(collateral.clone());
+        match &borrow_assets {
+            Some(assets) => env.storage().instance().set(&key, assets),
+            None => env.storage().instance().remove(&key),
+        }
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "allowed_borrows"), (collateral, borrow_assets)),
+        );
+
+        Ok(())
+    }
+
+    /// Get the borrow assets a collateral asset may back (`None` = any)
+    pub fn get_allowed_borrow_assets(env: Env, collateral: Address) -> Option<Vec<Address>> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AllowedBorrowAssets(collateral))
+    }
+
+    /// Get the debt ceiling of a borrow asset (token units, 0 if uncapped)
+    pub fn get_debt_ceiling(env: Env, asset: Address) -> i128 {
+        let ceiling: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::DebtCeiling(asset))
+            .unwrap_or(0);
+        Self::borrow_from_accounting(&env, ceiling)
+    }
+
+    /// Set the borrow origination fee and how it is charged (admin only)
+    ///
+    /// # Arguments
+    /// * `borrow_origination_fee_bp` - Fee in basis points of each borrow (max 1000)
+    /// * `mode` - Deduct the fee from the borrowed amount or add it to debt
+    pub fn set_origination_fee(
+        env: Env,
+        caller: Address,
+        borrow_origination_fee_bp: u32,
+        mode: OriginationFeeMode,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if borrow_origination_fee_bp > MAX_ORIGINATION_FEE_BP {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::OriginationFeeBp, &borrow_origination_fee_bp);
+        env.storage().instance().set(&DataKey::OriginationFeeMode, &mode);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "origination_fee"), (borrow_origination_fee_bp, mode)),
+        );
+
+        Ok(())
+    }
+
+    /// Get the borrow origination fee (basis points, 0 by default)
+    pub fn get_origination_fee_bp(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::OriginationFeeBp)
+            .unwrap_or(0)
+    }
+
+    /// Set the share of accrued interest taken as protocol fees (admin only)
+    ///
+    /// The fee is taken from interest as it is repaid, not when it accrues.
+    ///
+    /// # Arguments
+    /// * `reserve_factor_bp` - Share of interest in basis points (max 5000)
+    pub fn set_reserve_factor(
+        env: Env,
+        caller: Address,
+        reserve_factor_bp: u32,
+    ) -> Result<(), PoolError> {
+        caller.require_auth();
+        Self::require_admin(&env, &caller)?;
+
+        if reserve_factor_bp > MAX_RESERVE_FACTOR_BP {
+            return Err(PoolError::InvalidParams);
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ReserveFactor, &reserve_factor_bp);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("changed")),
+            (Symbol::new(&env, "reserve_factor"), reserve_factor_bp),
+        );
+
+        Ok(())
+    }
+
+    /// Get the reserve factor (basis points, 0 by default)
+    pub fn get_reserve_factor(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReserveFactor)
+            .unwrap_or(0)
+    }
+
+    /// Get how the origination fee is charged (deducted by default)
+    pub fn get_origination_fee_mode(env: Env) -> OriginationFeeMode {
+        env.storage()
+            .instance()
+            .get(&DataKey::OriginationFeeMode)
+            .unwrap_or_default()
+    }
 
     /// Update Blend pool address
+    ///
+    /// Rejects the admin and the pool's own address with `InvalidParams`.
+    /// Emits `("config", "updated")` with `(key, previous, new)`, where
+    /// `previous` is `None` if no address was set before.
     pub fn set_blend_pool(
         env: Env,
         caller: Address,
@@ -811,7 +3132,17 @@ impl VantisPoolContract {
     ) -> Result<(), PoolError> {
         caller.require_auth();
         Self::require_admin(&env, &caller)?;
+        Self::require_external_contract(&env, &blend_pool)?;
+
+        let previous: Option<Address> = env.storage().instance().get(&DataKey::BlendPool);
         env.storage().instance().set(&DataKey::BlendPool, &blend_pool);
+
+        Self::publish_event(
+            &env,
+            (symbol_short!("config"), symbol_short!("updated")),
+            (Symbol::new(&env, "blend_pool"), previous, blend_pool),
+        );
+
         Ok(())
     }
 }
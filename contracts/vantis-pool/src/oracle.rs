@@ -0,0 +1,121 @@
+//! Oracle adapter interface and price helpers
+
+use soroban_sdk::{contractclient, contracterror, contracttype, Address, Env, Symbol};
+
+use crate::PoolError;
+
+/// Price scalar used by the oracle adapter (14 decimals, Blend standard)
+pub const PRICE_SCALAR: i128 = 100_000_000_000_000;
+
+/// Price data returned by the oracle adapter
+///
+/// Mirrors `oracle_adapter::PriceData` so the pool can decode responses
+/// without linking the oracle contract crate.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PriceData {
+    /// Price in USD with 14 decimals
+    pub price: i128,
+    /// Timestamp of the price update
+    pub timestamp: u64,
+    /// Source identifier
+    pub source: Symbol,
+    /// Confidence in the price (basis points)
+    pub confidence: u32,
+}
+
+/// Peg band a stablecoin borrow asset must trade within
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PegCheck {
+    /// Oracle symbol of the borrow asset
+    pub symbol: Symbol,
+    /// Maximum deviation from $1 (basis points)
+    pub max_deviation_bp: u32,
+}
+
+impl PegCheck {
+    /// Whether a 14-decimal price is within the band around $1
+    pub fn is_pegged(&self, price: i128) -> bool {
+        (price - PRICE_SCALAR).abs() * 10000 <= PRICE_SCALAR * self.max_deviation_bp as i128
+    }
+}
+
+/// Oracle adapter failures the pool reports individually
+///
+/// Mirrors the matching `oracle_adapter::OracleError` codes. Any other
+/// failure surfaces as `PoolError::OracleError`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum OracleError {
+    /// Price is older than the staleness threshold
+    StalePrice = 3,
+    /// Price confidence is below the configured minimum
+    LowConfidence = 8,
+    /// Asset is paused by the oracle admin
+    AssetPaused = 9,
+}
+
+/// Subset of the oracle adapter interface used by the pool
+///
+/// Only the generated `OracleClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "OracleClient")]
+pub trait OracleInterface {
+    fn get_price(env: Env, asset: Symbol) -> Result<PriceData, OracleError>;
+    fn get_last_price(env: Env, asset: Symbol) -> Result<PriceData, OracleError>;
+}
+
+/// Fetch the price for an asset, treating a missing or zero price as an error
+///
+/// A feed glitch must never silently value collateral at zero, since that
+/// could mark a healthy position liquidatable. Stale, low-confidence and
+/// paused prices map to their own `PoolError` variants.
+pub fn get_asset_price(env: &Env, oracle: &Address, asset: &Symbol) -> Result<i128, PoolError> {
+    let client = OracleClient::new(env, oracle);
+
+    let price_data = match client.try_get_price(asset) {
+        Ok(Ok(data)) => data,
+        Err(Ok(OracleError::StalePrice)) => return Err(PoolError::StalePrice),
+        Err(Ok(OracleError::LowConfidence)) => return Err(PoolError::LowPriceConfidence),
+        Err(Ok(OracleError::AssetPaused)) => return Err(PoolError::PriceFeedPaused),
+        _ => return Err(PoolError::OracleError),
+    };
+
+    if price_data.price <= 0 {
+        return Err(PoolError::OracleError);
+    }
+
+    Ok(price_data.price)
+}
+
+/// Fetch a price for a read-only view, tolerating staleness
+///
+/// Behaves like `get_asset_price` but falls back to the oracle's last
+/// pushed price when the live one is stale. Returns the price and whether
+/// it was stale; must not be used for state-changing checks.
+pub fn get_view_price(
+    env: &Env,
+    oracle: &Address,
+    asset: &Symbol,
+) -> Result<(i128, bool), PoolError> {
+    match get_asset_price(env, oracle, asset) {
+        Ok(price) => Ok((price, false)),
+        Err(PoolError::StalePrice) => {
+            match OracleClient::new(env, oracle).try_get_last_price(asset) {
+                Ok(Ok(data)) if data.price > 0 => Ok((data.price, true)),
+                _ => Err(PoolError::StalePrice),
+            }
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Convert a collateral amount to its USD value using a 14-decimal price
+///
+/// `amount` is in accounting units (`ACCOUNTING_DECIMALS`), so the result is
+/// a USD value at the same precision.
+pub fn calculate_value(amount: i128, price: i128) -> i128 {
+    amount * price / PRICE_SCALAR
+}
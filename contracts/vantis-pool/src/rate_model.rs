@@ -0,0 +1,13 @@
+//! External interest rate model interface
+
+use soroban_sdk::{contractclient, Env};
+
+/// Interface implemented by `interest-rate-model` contracts
+///
+/// Only the generated `RateModelClient` is used; the trait itself is never called.
+#[allow(dead_code)]
+#[contractclient(name = "RateModelClient")]
+pub trait RateModelInterface {
+    /// Annual borrow rate (basis points) for a utilization (basis points)
+    fn rate(env: Env, utilization: u32) -> u32;
+}
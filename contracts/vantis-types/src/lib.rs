@@ -58,7 +58,7 @@ pub struct Request {
 #[derive(Clone, Debug)]
 pub struct Positions {
     /// Collateral positions: Map of asset index to amount
-    /// Represented as a flat vector: [index0, amount0, index1, amount1, ...]
+    /// Represented as a vector of (reserve index, amount) pairs
     pub collateral: Vec<(u32, i128)>,
     /// Liability positions (borrows): Map of asset index to amount
     pub liabilities: Vec<(u32, i128)>,
@@ -66,6 +66,37 @@ pub struct Positions {
     pub supply: Vec<(u32, i128)>,
 }
 
+impl Positions {
+    /// Collateral held at a reserve index (0 if none)
+    pub fn collateral_of(&self, index: u32) -> i128 {
+        amount_at(&self.collateral, index)
+    }
+
+    /// Liability owed at a reserve index (0 if none)
+    pub fn liability_of(&self, index: u32) -> i128 {
+        amount_at(&self.liabilities, index)
+    }
+
+    /// Sum of all liabilities, in each reserve's own units
+    pub fn total_liability(&self) -> i128 {
+        self.liabilities.iter().map(|(_, amount)| amount).sum()
+    }
+
+    /// Whether the user holds no collateral, liabilities or supply
+    pub fn is_empty(&self) -> bool {
+        self.collateral.is_empty() && self.liabilities.is_empty() && self.supply.is_empty()
+    }
+}
+
+/// Amount stored for a reserve index in a list of (index, amount) pairs
+fn amount_at(entries: &Vec<(u32, i128)>, index: u32) -> i128 {
+    entries
+        .iter()
+        .find(|(entry_index, _)| *entry_index == index)
+        .map(|(_, amount)| amount)
+        .unwrap_or(0)
+}
+
 /// Reserve configuration for a Blend pool asset
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -153,3 +184,67 @@ pub struct HealthFactorResult {
     /// Whether position is liquidatable
     pub is_liquidatable: bool,
 }
+
+/// Check that no address appears more than once
+///
+/// Used by contract initializers to catch wiring mistakes such as passing
+/// the oracle address where the token address belongs.
+pub fn addresses_distinct(addresses: &Vec<Address>) -> bool {
+    for (i, address) in addresses.iter().enumerate() {
+        for other in addresses.iter().skip(i + 1) {
+            if address == other {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Decimals used for USD values and oracle prices (Blend standard)
+pub const PRICE_DECIMALS: u32 = 14;
+
+/// Rescale a fixed-point amount from one decimal precision to another
+///
+/// Scaling down truncates toward zero, so the result never overstates
+/// the original amount.
+pub fn scale_decimals(amount: i128, from_decimals: u32, to_decimals: u32) -> i128 {
+    if from_decimals > to_decimals {
+        amount / 10i128.pow(from_decimals - to_decimals)
+    } else {
+        amount * 10i128.pow(to_decimals - from_decimals)
+    }
+}
+
+/// Default health-factor scale (basis points, 10000 = 1.0)
+pub const DEFAULT_HF_SCALE: i128 = 10000;
+
+/// Largest supported health-factor scale (1e12 = 1.0)
+pub const MAX_HF_SCALE: i128 = 1_000_000_000_000;
+
+/// Check that a health-factor scale is a power of ten within the supported range
+pub fn is_valid_hf_scale(scale: i128) -> bool {
+    let mut candidate = DEFAULT_HF_SCALE;
+    while candidate <= MAX_HF_SCALE {
+        if candidate == scale {
+            return true;
+        }
+        candidate *= 10;
+    }
+    false
+}
+
+/// Convert a health factor between two power-of-ten scales
+///
+/// `i128::MAX` (no debt) is passed through unchanged.
+pub fn rescale_hf(value: i128, from_scale: i128, to_scale: i128) -> i128 {
+    if value == i128::MAX {
+        value
+    } else if to_scale >= from_scale {
+        value * (to_scale / from_scale)
+    } else {
+        value / (from_scale / to_scale)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{vec, Env};
+
+fn sample_positions(env: &Env) -> Positions {
+    Positions {
+        collateral: vec![env, (0, 1000_0000000), (3, 250_0000000)],
+        liabilities: vec![env, (1, 400_0000000), (2, 50_0000000)],
+        supply: Vec::new(env),
+    }
+}
+
+#[test]
+fn test_positions_lookup_present_index() {
+    let env = Env::default();
+    let positions = sample_positions(&env);
+
+    assert_eq!(positions.collateral_of(0), 1000_0000000);
+    assert_eq!(positions.collateral_of(3), 250_0000000);
+    assert_eq!(positions.liability_of(2), 50_0000000);
+    assert_eq!(positions.total_liability(), 450_0000000);
+    assert!(!positions.is_empty());
+}
+
+#[test]
+fn test_positions_lookup_absent_index() {
+    let env = Env::default();
+    let positions = sample_positions(&env);
+
+    // Index 1 is only a liability, index 7 is not held at all
+    assert_eq!(positions.collateral_of(1), 0);
+    assert_eq!(positions.collateral_of(7), 0);
+    assert_eq!(positions.liability_of(0), 0);
+
+    let empty = Positions {
+        collateral: Vec::new(&env),
+        liabilities: Vec::new(&env),
+        supply: Vec::new(&env),
+    };
+    assert_eq!(empty.total_liability(), 0);
+    assert!(empty.is_empty());
+
+    // Supply alone still counts as a position
+    let supplier = Positions {
+        supply: vec![&env, (0, 10_0000000)],
+        ..empty
+    };
+    assert!(!supplier.is_empty());
+}
@@ -64,6 +64,7 @@ export DEFAULT_BASE_RATE=200              # 2% base APR
 export DEFAULT_SLOPE1=400                 # 4% slope below optimal
 export DEFAULT_SLOPE2=7500                # 75% slope above optimal
 export DEFAULT_OPTIMAL_UTILIZATION=8000   # 80% optimal utilization
+export DEFAULT_MAX_BORROW_RATE=10000     # 100% APR cap
 
 # =============================================================================
 # Collateral Asset Configuration
@@ -262,7 +262,7 @@ initialize_all_contracts() {
         --oracle "$ORACLE_ADDRESS" \
         --xlm_token "$XLM_ADDRESS" \
         --blend_pool_address "$BLEND_ADAPTER_ADDRESS" \
-        --interest_params '{"base_rate":'"${DEFAULT_BASE_RATE}"',"slope1":'"${DEFAULT_SLOPE1}"',"slope2":'"${DEFAULT_SLOPE2}"',"optimal_utilization":'"${DEFAULT_OPTIMAL_UTILIZATION}"'}' \
+        --interest_params '{"base_rate":'"${DEFAULT_BASE_RATE}"',"slope1":'"${DEFAULT_SLOPE1}"',"slope2":'"${DEFAULT_SLOPE2}"',"optimal_utilization":'"${DEFAULT_OPTIMAL_UTILIZATION}"',"max_borrow_rate":'"${DEFAULT_MAX_BORROW_RATE}"'}' \
         2>&1)
 
     if [[ $? -ne 0 ]]; then
@@ -312,7 +312,7 @@ configure_contracts() {
         --network testnet \
         -- add_asset \
         --caller "$ADMIN_ADDRESS" \
-        --config '{"symbol":"XLM","contract":"'"${XLM_ADDRESS}"'","decimals":7,"base_ltv":'"${XLM_COLLATERAL_FACTOR}"',"liquidation_threshold":'"${XLM_LIQUIDATION_THRESHOLD}"'}' \
+        --config '{"symbol":"XLM","contract":"'"${XLM_ADDRESS}"'","decimals":7,"base_ltv":'"${XLM_COLLATERAL_FACTOR}"',"liquidation_threshold":'"${XLM_LIQUIDATION_THRESHOLD}"',"staleness_threshold":0,"feed_decimals":14}' \
         2>&1)
     
     if [[ $? -ne 0 ]]; then
@@ -357,7 +357,7 @@ configure_contracts() {
         --network testnet \
         -- add_collateral_asset \
         --caller "$ADMIN_ADDRESS" \
-        --config '{"token":"'"${XLM_ADDRESS}"'","symbol":"XLM","collateral_factor":'"${XLM_COLLATERAL_FACTOR}"',"liquidation_threshold":'"${XLM_LIQUIDATION_THRESHOLD}"',"liquidation_penalty":'"${XLM_LIQUIDATION_PENALTY}"',"is_active":true}' \
+        --config '{"token":"'"${XLM_ADDRESS}"'","symbol":"XLM","collateral_factor":'"${XLM_COLLATERAL_FACTOR}"',"liquidation_threshold":'"${XLM_LIQUIDATION_THRESHOLD}"',"liquidation_penalty":'"${XLM_LIQUIDATION_PENALTY}"',"is_active":true,"decimals":7,"ramp":"Disabled"}' \
         2>&1)
     
     if [[ $? -ne 0 ]]; then
@@ -403,6 +403,7 @@ configure_contracts() {
         --caller "$ADMIN_ADDRESS" \
         --asset XLM \
         --price "$TEST_PRICE_XLM" \
+        --confidence 10000 \
         2>&1)
     
     if [[ $? -ne 0 ]]; then
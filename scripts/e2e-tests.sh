@@ -193,6 +193,7 @@ test_oracle_update_price() {
         "--caller" "$ADMIN_ADDRESS" \
         "--asset" "XLM" \
         "--price" "$new_price" \
+        "--confidence" "10000" \
         2>/dev/null
 
     # Verify price was updated
@@ -217,7 +218,8 @@ test_oracle_get_price() {
     local update_result=$(invoke_contract "$ORACLE_ADDRESS" "update_price" "admin" \
         "--caller" "$ADMIN_ADDRESS" \
         "--asset" "XLM" \
-        "--price" "$new_price" 2>&1)
+        "--price" "$new_price" \
+        "--confidence" "10000" 2>&1)
 
     if [[ "$update_result" == *"error"* ]] || [[ "$update_result" == *"Error"* ]]; then
         log_warning "Price update failed (may need asset to be added first): ${update_result}"
@@ -261,6 +263,7 @@ test_oracle_get_volatility() {
         "--caller" "$ADMIN_ADDRESS" \
         "--asset" "XLM" \
         "--price" "$new_price" \
+        "--confidence" "10000" \
         2>/dev/null || true
 
     local result=$(read_contract "$ORACLE_ADDRESS" "get_volatility" \
@@ -654,6 +657,7 @@ test_integration_oracle_price_feeds() {
         "--caller" "$ADMIN_ADDRESS" \
         "--asset" "XLM" \
         "--price" "$test_price" \
+        "--confidence" "10000" \
         2>/dev/null || true
 
     log_success "Oracle price feed test completed"